@@ -86,7 +86,7 @@ impl GenAst {
             let type_split: Vec<&str> = t.split(';').collect();
             let type_name = type_split[0].trim();
             let type_fields = type_split[1].trim();
-            GenAst::define_type(f, type_name, type_fields, &basename)?;
+            GenAst::define_type(f, type_name, type_fields, basename)?;
         }
         Ok(())
     }
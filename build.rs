@@ -13,38 +13,49 @@ fn main() -> io::Result<()> {
         "Variable ; name: Token".to_string(),
         "Assign ; name: Token, value: Rc<Expr>".to_string(),
         "CompoundAssign ; name: Token, operator: Token, value: Rc<Expr>".to_string(),
-        "Call ; callee: Rc<Expr>, paren: Token, args: Vec<Rc<Expr>>".to_string(),
-        "Lambda ; params: Rc<Vec<Token>>, body: Rc<Vec<Rc<Stmt>>>".to_string(),
+        "Call ; callee: Rc<Expr>, paren: Token, args: Vec<Rc<Expr>>, keywords: Vec<Token>".to_string(),
+        "Lambda ; params: Rc<Vec<Token>>, defaults: Rc<Vec<Rc<Expr>>>, is_variadic: bool, body: Rc<Vec<Rc<Stmt>>>".to_string(),
         "Array ; arr: Vec<Rc<Expr>>".to_string(),
+        "Spread ; token: Token, expr: Rc<Expr>".to_string(),
+        "Slice ; bracket: Token, start: Option<Rc<Expr>>, end: Option<Rc<Expr>>, step: Option<Rc<Expr>>".to_string(),
+        "Optional ; token: Token, object: Rc<Expr>".to_string(),
+        "Chain ; expr: Rc<Expr>".to_string(),
         "Index ; identifier: Rc<Expr>, bracket: Token, index: Rc<Expr>".to_string(),
         "UpdateIndex ; name: Token, identifier: Rc<Expr>, bracket: Token, index: Rc<Expr>, value: Rc<Expr>".to_string(),
         "Get ; object: Rc<Expr>, name: Token".to_string(),
         "Set ; object: Rc<Expr>, name: Token, value: Rc<Expr>, operator: Token".to_string(),
         "Update ; var: Rc<Expr>, operator: Token, prefix: bool".to_string(),
         "This ; keyword: Token".to_string(),
+        "Match ; keyword: Token, value: Rc<Expr>, arms: Vec<Rc<MatchArm>>".to_string(),
     ];
 
     let expr_mods = vec![
         "crate::lexer::{token::Token, literal::Literal}".to_string(),
         "crate::error::LoxResult".to_string(),
         "super::stmt::*".to_string(),
+        "super::pattern::MatchArm".to_string(),
         "std::rc::Rc".to_string(),
         "std::hash::{Hash, Hasher}".to_string()
     ];
 
     let stmt_type = vec![
         "Expression ; expr: Rc<Expr>".to_string(),
-        "Let ; name: Token, initializer: Option<Rc<Expr>>".to_string(),
+        "Let ; name: Token, initializer: Option<Rc<Expr>>, is_const: bool".to_string(),
+        "Destructure ; target: Destructure, initializer: Rc<Expr>".to_string(),
         "Block ; statements: Vec<Rc<Stmt>>".to_string(),
         "If ; condition: Rc<Expr>, then_branch: Rc<Stmt>, else_branch: Option<Rc<Stmt>>".to_string(),
         "While ; condition: Rc<Expr>, body: Rc<Stmt>".to_string(),
         "For ; var: Option<Rc<Stmt>>, condition: Option<Rc<Expr>>, update_expr: Option<Rc<Expr>>, body: Rc<Stmt>".to_string(),
         "Break ; token: Token".to_string(),
         "Continue ; token: Token".to_string(),
-        "Function ; name: Token, params: Rc<Vec<Token>>, body: Rc<Vec<Rc<Stmt>>>, is_static: bool, is_pub: bool".to_string(),
+        "Function ; name: Token, params: Rc<Vec<Token>>, defaults: Rc<Vec<Rc<Expr>>>, is_variadic: bool, body: Rc<Vec<Rc<Stmt>>>, is_static: bool, is_pub: bool".to_string(),
         "Return ; keyword: Token, value: Rc<Expr>".to_string(),
-        "Class ; name: Token, fields: Vec<Rc<Stmt>>, methods: Vec<Rc<Stmt>>".to_string(),
-        "Field ; name: Token, is_pub: bool, initializer: Option<Rc<Expr>>, is_static: bool".to_string(),
+        "Class ; name: Token, fields: Vec<Rc<Stmt>>, methods: Vec<Rc<Stmt>>, getters: Vec<Rc<Stmt>>, setters: Vec<Rc<Stmt>>, is_abstract: bool, interfaces: Vec<Token>".to_string(),
+        "Field ; name: Token, is_pub: bool, initializer: Option<Rc<Expr>>, is_static: bool, is_const: bool".to_string(),
+        "Signature ; name: Token, params: Rc<Vec<Token>>, is_pub: bool".to_string(),
+        "Interface ; name: Token, methods: Vec<Rc<Stmt>>".to_string(),
+        "Enum ; name: Token, variants: Vec<Rc<Stmt>>".to_string(),
+        "Variant ; name: Token, fields: Option<Rc<Vec<Token>>>".to_string(),
     ];
 
     let stmt_mods = vec![
@@ -52,6 +63,7 @@ fn main() -> io::Result<()> {
         "crate::error::LoxResult".to_string(),
         "std::rc::Rc".to_string(),
         "super::expr::*".to_string(),
+        "super::pattern::Destructure".to_string(),
         "std::hash::{Hash, Hasher}".to_string()
     ];
 
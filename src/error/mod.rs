@@ -151,5 +151,7 @@ pub enum LoxResult {
     Break,
     Continue,
     Return(Literal),
-    Message(String)
+    Message(String),
+    // Raised by `a?.b` when `a` is none, caught at the end of the chain
+    ShortCircuit,
 }
@@ -2,6 +2,7 @@ use crate::loxlib::array::loxarray::LoxArray;
 use crate::loxlib::number::loxnumber::LoxNumber;
 use crate::loxlib::string::loxstring::LoxString;
 use crate::runtime::loxfunction::LoxFunction;
+use crate::runtime::loxenum::{LoxEnum, LoxVariant};
 use crate::runtime::{loxclass::LoxClass, loxinstance::LoxInstance};
 
 use crate::loxlib::loxnatives::*;
@@ -10,6 +11,13 @@ use std::fmt::{Display, Formatter};
 use std::ops::{Add, Div, Mul, Sub};
 use std::rc::Rc;
 
+const MAX_LINE_WIDTH: usize = 80;
+const INDENT: &str = "    ";
+
+// Lets the interpreter substitute the result of a user defined `toString()`
+// for the structural rendering of an instance.
+pub type InstanceToString<'a> = dyn Fn(&Rc<LoxInstance>) -> Option<String> + 'a;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum Literal {
     Number(Rc<LoxNumber>),
@@ -20,6 +28,8 @@ pub enum Literal {
     Class(Rc<LoxClass>),
     Instance(Rc<LoxInstance>),
     Array(Rc<LoxArray>),
+    Enum(Rc<LoxEnum>),
+    Variant(Rc<LoxVariant>),
     None,
     LiteralNone,
 }
@@ -32,9 +42,10 @@ impl Display for Literal {
             Self::None => write!(f, "none"),
             Self::Bool(bool) => write!(f, "{bool}"),
             Self::Func(_) => write!(f, "_Function_"),
-            Self::Array(_) => write!(f, "Array []"),
-            Self::Class(c) => write!(f, "{c}",),
-            Self::Instance(i) => write!(f, "{i}"),
+            Self::Array(_) | Self::Class(_) | Self::Instance(_) | Self::Variant(_) => {
+                write!(f, "{}", self.repr())
+            }
+            Self::Enum(e) => write!(f, "{e}"),
             Self::Native(n) => write!(f, "{n}"),
             Self::LiteralNone => write!(f, "_LiteralNone_"), // This none is for internal use only
         }
@@ -74,6 +85,9 @@ impl Literal {
             Self::Func(_) => "Function".to_string(),
             Self::Class(_) => "Class".to_string(),
             Self::Array(_) => "Array".to_string(),
+            Self::Instance(i) => i.to_string(),
+            Self::Enum(_) => "Enum".to_string(),
+            Self::Variant(v) => v.owner.name.to_string(),
             _ => self.to_string(),
         }
     }
@@ -85,53 +99,141 @@ impl Literal {
         false
     }
 
-    pub fn equals(self, rhs: Self) -> bool {
-        if self.get_value() == rhs.get_value() {
-            return true;
+    pub fn get_value(&self) -> String {
+        self.get_value_with(&|_| None)
+    }
+
+    pub fn get_value_with(&self, to_string: &InstanceToString) -> String {
+        match self {
+            Self::Str(str) => str.string.borrow().to_string(),
+            _ => self.repr_with(to_string),
         }
+    }
 
-        false
+    pub fn repr(&self) -> String {
+        self.repr_with(&|_| None)
     }
 
-    pub fn get_value(&self) -> String {
+    pub fn repr_with(&self, to_string: &InstanceToString) -> String {
+        self.structural(&mut Vec::new(), 0, to_string)
+    }
+
+    // Renders nested arrays, instances and classes, `seen` holds the containers
+    // currently being rendered so that a value containing itself prints as `[...]`.
+    fn structural(
+        &self,
+        seen: &mut Vec<*const ()>,
+        depth: usize,
+        to_string: &InstanceToString,
+    ) -> String {
         match self {
             Self::Number(num) => num.num.borrow().to_string(),
-            Self::Str(str) => str.string.borrow().to_string(),
+            Self::Str(str) => {
+                let mut repr = String::from("\"");
+                for ch in str.string.borrow().chars() {
+                    match ch {
+                        '"' => repr.push_str("\\\""),
+                        '\\' => repr.push_str("\\\\"),
+                        '\n' => repr.push_str("\\n"),
+                        '\t' => repr.push_str("\\t"),
+                        '\r' => repr.push_str("\\r"),
+                        _ => repr.push(ch),
+                    }
+                }
+                repr.push('"');
+                repr
+            }
             Self::Bool(bool) => bool.to_string(),
-            Self::None => String::from("none"),
+            Self::None | Self::LiteralNone => String::from("none"),
             Self::Func(func) => func.to_string(),
-            Self::Class(class) => class.to_string(),
             Self::Native(n) => n.to_string(),
-            Self::Instance(i) => i.to_string(),
+            Self::Enum(e) => e.to_string(),
+            Self::Variant(v) => {
+                let name = format!("{}.{}", v.owner.name, v.name);
+                if v.is_unit() {
+                    return name;
+                }
+                let values: Vec<String> = v
+                    .values
+                    .iter()
+                    .map(|val| val.structural(seen, depth + 1, to_string))
+                    .collect();
+                Literal::layout(&format!("{name}("), values, ")", "", depth)
+            }
             Self::Array(arr) => {
-                let mut str = "[".to_string();
-                let len = arr.array.borrow().len();
-                for (i, el) in arr.array.borrow().iter().enumerate() {
-                    str.push_str(&el.get_value());
-                    if len > 1 && len - 1 != i {
-                        str.push_str(", ");
-                    }
+                let ptr = Rc::as_ptr(&arr.array) as *const ();
+                if seen.contains(&ptr) {
+                    return String::from("[...]");
                 }
-                str.push(']');
-                str
+                seen.push(ptr);
+                let elems: Vec<String> = arr
+                    .array
+                    .borrow()
+                    .iter()
+                    .map(|el| el.structural(seen, depth + 1, to_string))
+                    .collect();
+                seen.pop();
+                Literal::layout("[", elems, "]", "", depth)
+            }
+            Self::Instance(i) => {
+                if let Some(str) = to_string(i) {
+                    return str;
+                }
+                let ptr = Rc::as_ptr(i) as *const ();
+                if seen.contains(&ptr) {
+                    return format!("{} {{...}}", i.class_name());
+                }
+                seen.push(ptr);
+                let fields: Vec<String> = i
+                    .public_fields()
+                    .iter()
+                    .map(|(name, val)| {
+                        format!("{name}: {}", val.structural(seen, depth + 1, to_string))
+                    })
+                    .collect();
+                seen.pop();
+                Literal::layout(&format!("{} {{", i.class_name()), fields, "}", " ", depth)
+            }
+            Self::Class(c) => {
+                let ptr = Rc::as_ptr(c) as *const ();
+                if seen.contains(&ptr) {
+                    return format!("class {} {{...}}", c.name);
+                }
+                seen.push(ptr);
+                let fields: Vec<String> = c
+                    .static_fields()
+                    .iter()
+                    .map(|(name, val)| {
+                        format!("{name}: {}", val.structural(seen, depth + 1, to_string))
+                    })
+                    .collect();
+                seen.pop();
+                Literal::layout(&format!("class {} {{", c.name), fields, "}", " ", depth)
             }
-            Self::LiteralNone => String::from("none"),
         }
     }
 
-    pub fn print_value(&self) {
-        match self {
-            Self::Number(num) => println!("{}", num.num.borrow()),
-            Self::Str(str) => println!("{}", str.string.borrow()),
-            Self::Bool(bool) => println!("{bool}"),
-            Self::Func(func) => println!("{func}"),
-            Self::Class(class) => println!("{class}"),
-            Self::Instance(i) => println!("{i}"),
-            Self::Native(n) => println!("{n}"),
-            Self::Array(_) => println!("{}", self.get_value()),
-            Self::None => println!("{}", self),
-            Self::LiteralNone => println!("{}", Literal::None),
+    fn layout(open: &str, items: Vec<String>, close: &str, pad: &str, depth: usize) -> String {
+        if items.is_empty() {
+            return format!("{open}{close}");
+        }
+
+        let line = format!("{open}{pad}{}{pad}{close}", items.join(", "));
+        if depth * INDENT.len() + line.len() <= MAX_LINE_WIDTH
+            && !items.iter().any(|item| item.contains('\n'))
+        {
+            return line;
         }
+
+        let mut block = format!("{open}\n");
+        for item in items.iter() {
+            block.push_str(&INDENT.repeat(depth + 1));
+            block.push_str(item);
+            block.push_str(",\n");
+        }
+        block.push_str(&INDENT.repeat(depth));
+        block.push_str(close);
+        block
     }
 
     pub fn dup(&self) -> Self {
@@ -145,6 +247,8 @@ impl Literal {
             Self::Class(class) => Self::Class(class.clone()),
             Self::Instance(i) => Self::Instance(i.clone()),
             Self::Native(n) => Self::Native(n.clone()),
+            Self::Enum(e) => Self::Enum(e.clone()),
+            Self::Variant(v) => Self::Variant(v.clone()),
             Self::LiteralNone => Self::LiteralNone,
         }
     }
@@ -213,3 +317,58 @@ impl Div<Literal> for Literal {
         Err(format!("while trying to divide {} and {}", self, rhs))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::tests::eval;
+
+    fn num(n: f64) -> Literal {
+        Literal::Number(Rc::new(LoxNumber::new(n)))
+    }
+
+    fn array(elems: Vec<Literal>) -> Literal {
+        Literal::Array(Rc::new(LoxArray::new(elems)))
+    }
+
+    #[test]
+    fn short_values_stay_on_one_line() {
+        let nested = array(vec![num(1.0), array(vec![num(2.0), num(3.0)]), array(Vec::new())]);
+        assert_eq!(nested.repr(), "[1, [2, 3], []]");
+    }
+
+    #[test]
+    fn long_values_break_into_lines() {
+        let long = array((0..30).map(|n| num(n as f64)).collect());
+        let expected = format!(
+            "[\n{}]",
+            (0..30).map(|n| format!("    {n},\n")).collect::<String>()
+        );
+        assert_eq!(long.repr(), expected);
+
+        let outer = array(vec![num(1.0), long]);
+        // Nested blocks are indented one more level
+        let repr = outer.repr();
+        assert!(repr.starts_with("[\n    1,\n    [\n        0,\n"));
+        assert!(repr.ends_with("        29,\n    ],\n]"));
+    }
+
+    #[test]
+    fn cycles_are_elided() {
+        let inner = Rc::new(LoxArray::new(vec![num(1.0)]));
+        inner.array.borrow_mut().push(Literal::Array(inner.clone()));
+        assert_eq!(Literal::Array(inner.clone()).repr(), "[1, [...]]");
+
+        // The same array twice side by side is not a cycle
+        let shared = array(vec![num(2.0)]);
+        assert_eq!(array(vec![shared.dup(), shared]).repr(), "[[2], [2]]");
+        inner.array.borrow_mut().clear();
+    }
+
+    #[test]
+    fn instances_and_classes() {
+        let src = "class Node { public next; public v = 1; static count = 2; init() { this.next = this; } }
+            let r = [Node(), Node];";
+        assert_eq!(eval(src, "r"), "[Node { next: Node {...}, v: 1 }, class Node { count: 2 }]");
+    }
+}
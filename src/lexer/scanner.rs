@@ -204,7 +204,19 @@ impl<'a> Scanner<'a> {
             '[' => self.add_token(TokenType::LeftBracket),
             ']' => self.add_token(TokenType::RightBracket),
             ',' => self.add_token(TokenType::Comma),
-            '.' => self.add_token(TokenType::Dot),
+            '.' => {
+                let token = if !self.is_match('.') {
+                    TokenType::Dot
+                } else if self.is_match('.') {
+                    TokenType::DotDotDot
+                } else if self.is_match('=') {
+                    TokenType::DotDotEqual
+                } else {
+                    TokenType::DotDot
+                };
+                self.add_token(token);
+            }
+            '|' => self.add_token(TokenType::Pipe),
             '-' => {
                 let token = if self.is_match('=') {
                     TokenType::MinusEqual
@@ -233,7 +245,18 @@ impl<'a> Scanner<'a> {
                 };
                 self.add_token(token);
             }
-            '?' => self.add_token(TokenType::QuestionMark),
+            '?' => {
+                let token = if self.is_match('.') {
+                    TokenType::QuestionDot
+                } else if !self.is_match('?') {
+                    TokenType::QuestionMark
+                } else if self.is_match('=') {
+                    TokenType::QuestionQuestionEqual
+                } else {
+                    TokenType::QuestionQuestion
+                };
+                self.add_token(token);
+            }
             ':' => self.add_token(TokenType::Colon),
             '*' => {
                 let token = if self.is_match('=') {
@@ -255,6 +278,8 @@ impl<'a> Scanner<'a> {
             '=' => {
                 let token = if self.is_match('=') {
                     TokenType::Equals
+                } else if self.is_match('>') {
+                    TokenType::Arrow
                 } else {
                     TokenType::Assign
                 };
@@ -289,7 +314,7 @@ impl<'a> Scanner<'a> {
                     self.add_token(TokenType::Slash);
                 }
             }
-            _ if c.is_ascii_alphabetic() => self.identifier(),
+            _ if Scanner::is_alpha(c) => self.identifier(),
             '0'..='9' => self.number(),
             '"' => self.string()?,
             ' ' => (),
@@ -351,6 +376,12 @@ impl<'a> Scanner<'a> {
         hmap.insert(String::from("static"), TokenType::Static);
         hmap.insert(String::from("public"), TokenType::Public);
         hmap.insert(String::from("private"), TokenType::Private);
+        hmap.insert(String::from("abstract"), TokenType::Abstract);
+        hmap.insert(String::from("interface"), TokenType::Interface);
+        hmap.insert(String::from("implements"), TokenType::Implements);
+        hmap.insert(String::from("enum"), TokenType::Enum);
+        hmap.insert(String::from("match"), TokenType::Match);
+        hmap.insert(String::from("const"), TokenType::Const);
     }
 
     fn get_literal_type(&self, token: &TokenType) -> Literal {
@@ -576,6 +607,91 @@ mod tests {
         }
     }
 
+    #[test]
+    fn pattern_tokens() {
+        let src = "1 | 2 => 1..5 1..=5 a.b ...c";
+        let e_handler = LoxErrorHandler::new();
+        let mut s = Scanner::new(src, &e_handler);
+        let expected_token = vec![
+            TokenType::Number,
+            TokenType::Pipe,
+            TokenType::Number,
+            TokenType::Arrow,
+            TokenType::Number,
+            TokenType::DotDot,
+            TokenType::Number,
+            TokenType::Number,
+            TokenType::DotDotEqual,
+            TokenType::Number,
+            TokenType::Identifier,
+            TokenType::Dot,
+            TokenType::Identifier,
+            TokenType::DotDotDot,
+            TokenType::Identifier,
+            TokenType::EOF,
+        ];
+
+        match s.scan_tokens() {
+            Ok(toks) => {
+                assert_eq!(expected_token.len(), toks.len());
+                for (tok, expected) in toks.iter().zip(expected_token.iter()) {
+                    assert_eq!(&tok.token_type, expected);
+                }
+            }
+            Err(_) => panic!("failed"),
+        }
+    }
+
+    #[test]
+    fn optional_chaining_tokens() {
+        let src = "a?.b ?? c ??= d ? e : f";
+        let e_handler = LoxErrorHandler::new();
+        let mut s = Scanner::new(src, &e_handler);
+        let expected_token = vec![
+            TokenType::Identifier,
+            TokenType::QuestionDot,
+            TokenType::Identifier,
+            TokenType::QuestionQuestion,
+            TokenType::Identifier,
+            TokenType::QuestionQuestionEqual,
+            TokenType::Identifier,
+            TokenType::QuestionMark,
+            TokenType::Identifier,
+            TokenType::Colon,
+            TokenType::Identifier,
+            TokenType::EOF,
+        ];
+
+        match s.scan_tokens() {
+            Ok(toks) => {
+                assert_eq!(expected_token.len(), toks.len());
+                for (tok, expected) in toks.iter().zip(expected_token.iter()) {
+                    assert_eq!(&tok.token_type, expected);
+                }
+            }
+            Err(_) => panic!("failed"),
+        }
+    }
+
+    #[test]
+    fn underscore_identifiers() {
+        let src = "__add__ _private";
+        let e_handler = LoxErrorHandler::new();
+        let mut s = Scanner::new(src, &e_handler);
+        let lexemes = vec![String::from("__add__"), String::from("_private")];
+
+        match s.scan_tokens() {
+            Ok(toks) => {
+                assert_eq!(toks.len(), 3);
+                for (i, lexeme) in lexemes.iter().enumerate() {
+                    assert_eq!(toks[i].token_type, TokenType::Identifier);
+                    assert_eq!(&toks[i].lexeme, lexeme);
+                }
+            }
+            Err(_) => panic!("failed"),
+        }
+    }
+
     #[test]
     fn unknown_character() {
         let src = "$";
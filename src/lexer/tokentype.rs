@@ -17,6 +17,10 @@ pub enum TokenType {
     Star,
     QuestionMark,
     Colon,
+    Pipe,
+    QuestionDot,
+    QuestionQuestion,
+    QuestionQuestionEqual,
 
     // One or two character tokens.
     Bang,
@@ -34,6 +38,10 @@ pub enum TokenType {
     GreaterEqual,
     Less,
     LessEqual,
+    Arrow,
+    DotDot,
+    DotDotEqual,
+    DotDotDot,
 
     // Literals.
     Identifier,
@@ -62,6 +70,12 @@ pub enum TokenType {
     Static,
     Public,
     Private,
+    Abstract,
+    Interface,
+    Implements,
+    Enum,
+    Match,
+    Const,
 
     EOF,
 }
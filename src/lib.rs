@@ -31,6 +31,7 @@ impl Lox {
         let resolver = Resolver::new(&self.interpreter);
         if let Ok(tokens) = scanner.scan_tokens() {
             let mut parser = Parser::new(tokens);
+            parser.set_is_repl(self.is_repl);
 
             if let Ok(stmts) = parser.parse() {
                 if let Ok(()) = resolver.resolve(&stmts) {
@@ -61,10 +62,14 @@ impl Lox {
         loop {
             print!("> ");
             io::Write::flush(&mut io::stdout()).expect("Flush failed.");
-            io::stdin().read_line(&mut input).unwrap_or_else(|error| {
+            let read = io::stdin().read_line(&mut input).unwrap_or_else(|error| {
                 eprintln!("Error: {error}");
                 process::exit(1);
             });
+            if read == 0 {
+                println!();
+                break;
+            }
             if input.trim() == "quit" || input.trim() == "exit" {
                 break;
             }
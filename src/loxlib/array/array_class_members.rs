@@ -23,11 +23,34 @@ impl ArrayMembers {
         map.insert(String::from("replace"), replace::Replace::new(self.array.clone()));
         map.insert(String::from("insert"), insert::Insert::new(self.array.clone()));
         map.insert(String::from("delete"), delete::Delete::new(self.array.clone()));
+        map.insert(String::from("contains"), contains::Contains::new(self.array.clone()));
+        map.insert(String::from("index_of"), index_of::IndexOf::new(self.array.clone()));
+        map.insert(String::from("map"), map::Map::new(self.array.clone()));
+        map.insert(String::from("filter"), filter::Filter::new(self.array.clone()));
+        map.insert(String::from("reduce"), reduce::Reduce::new(self.array.clone()));
+        map.insert(String::from("for_each"), for_each::ForEach::new(self.array.clone()));
+        map.insert(String::from("find"), find::Find::new(self.array.clone()));
+        map.insert(String::from("find_index"), find_index::FindIndex::new(self.array.clone()));
+        map.insert(String::from("any"), any::Any::new(self.array.clone()));
+        map.insert(String::from("all"), all::All::new(self.array.clone()));
+        map.insert(String::from("flat_map"), flat_map::FlatMap::new(self.array.clone()));
+        map.insert(String::from("sort"), sort::Sort::new(self.array.clone()));
+        map.insert(String::from("sort_by"), sort_by::SortBy::new(self.array.clone()));
+        map.insert(String::from("reverse"), reverse::Reverse::new(self.array.clone()));
+        map.insert(String::from("join"), join::Join::new(self.array.clone()));
+        map.insert(String::from("concat"), concat::Concat::new(self.array.clone()));
+        map.insert(String::from("slice"), slice::Slice::new(self.array.clone()));
+        map.insert(String::from("fill"), fill::Fill::new(self.array.clone()));
+        map.insert(String::from("unique"), unique::Unique::new(self.array.clone()));
+        map.insert(String::from("zip"), zip::Zip::new(self.array.clone()));
+        map.insert(String::from("chunks"), chunks::Chunks::new(self.array.clone()));
         map
     }
 
     pub fn get_statics(&self) -> HashMap<String, Literal> {
-        HashMap::new()
+        let mut map: HashMap<String, Literal> = HashMap::new();
+        map.insert(String::from("range"), range::Range::new());
+        map
     }
 
     pub fn get_fields(&self) -> HashMap<String, InstanceField> {
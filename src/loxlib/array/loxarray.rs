@@ -52,6 +52,6 @@ impl LoxArray {
     }
 
     pub fn get(&self, name: &Token) -> Result<Literal, LoxResult> {
-        return self.arr_inst.get(name, &self.arr_inst)
+        self.arr_inst.get(name, &self.arr_inst, false)
     }
 }
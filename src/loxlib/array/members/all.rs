@@ -0,0 +1,55 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{array::ArrayData, loxnatives::LoxNative},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct All {
+    array: ArrayData,
+}
+
+impl All {
+    pub fn new(array: ArrayData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("all", Rc::new(Self { array }), true)))
+    }
+}
+
+impl LoxCallable for All {
+    fn call(&self, interpreter: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let interpreter = interpreter.expect("Interpreter was not provided for Array.all");
+        let callback = args.first().expect("Array.all callback was null");
+
+        let elems = self.array.borrow().clone();
+        for el in elems.into_iter() {
+            if !interpreter.is_truthy(&interpreter.call_value(callback, vec![el])?) {
+                return Ok(Literal::Bool(false));
+            }
+        }
+        Ok(Literal::Bool(true))
+    }
+
+    fn arity(&self) -> usize {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn all() {
+        let (interpreter, f) = callback("let f = lm(x) => x > 0;");
+        let all = All { array: numbers(&[1.0, 2.0]) };
+        let not_all = All { array: numbers(&[1.0, 0.0]) };
+        let empty = All { array: numbers(&[]) };
+        assert_eq!(all.call(Some(&interpreter), vec![f.dup()]).unwrap(), Literal::Bool(true));
+        assert_eq!(not_all.call(Some(&interpreter), vec![f.dup()]).unwrap(), Literal::Bool(false));
+        assert_eq!(empty.call(Some(&interpreter), vec![f]).unwrap(), Literal::Bool(true));
+    }
+}
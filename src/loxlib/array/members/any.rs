@@ -0,0 +1,57 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{array::ArrayData, loxnatives::LoxNative},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Any {
+    array: ArrayData,
+}
+
+impl Any {
+    pub fn new(array: ArrayData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("any", Rc::new(Self { array }), true)))
+    }
+}
+
+impl LoxCallable for Any {
+    fn call(&self, interpreter: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let interpreter = interpreter.expect("Interpreter was not provided for Array.any");
+        let callback = args.first().expect("Array.any callback was null");
+
+        let elems = self.array.borrow().clone();
+        for el in elems.into_iter() {
+            if interpreter.is_truthy(&interpreter.call_value(callback, vec![el])?) {
+                return Ok(Literal::Bool(true));
+            }
+        }
+        Ok(Literal::Bool(false))
+    }
+
+    fn arity(&self) -> usize {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use crate::runtime::tests::global;
+    use super::*;
+
+    #[test]
+    fn any() {
+        let source = "let calls = 0; let f = lm(x) { calls += 1; return x < 0; };";
+        let (interpreter, f) = callback(source);
+        let any = Any { array: numbers(&[1.0, -1.0, 2.0]) };
+        assert_eq!(any.call(Some(&interpreter), vec![f.dup()]).unwrap(), Literal::Bool(true));
+        // Stops at the first match
+        assert_eq!(global(&interpreter, "calls").unwrap_number(), 2.0);
+        let empty = Any { array: numbers(&[]) };
+        assert_eq!(empty.call(Some(&interpreter), vec![f]).unwrap(), Literal::Bool(false));
+    }
+}
@@ -0,0 +1,81 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{array::{loxarray::LoxArray, ArrayData}, loxnatives::LoxNative},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Chunks {
+    array: ArrayData,
+}
+
+impl Chunks {
+    pub fn new(array: ArrayData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("chunks", Rc::new(Self { array }), true)))
+    }
+}
+
+impl LoxCallable for Chunks {
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let size = args.first().expect("Array.chunks size was null");
+        if size.get_typename() != "Number" {
+            return Err(LoxResult::Message(format!(
+                "Expected type 'Number' for 'size' in Array.chunks(size) got '{}'",
+                size.get_typename()
+            )));
+        }
+        // Also rejects NaN, infinity and fractions
+        let size = size.unwrap_number();
+        if size.is_nan() || size < 1.0 || size.fract() != 0.0 {
+            return Err(LoxResult::Message(format!(
+                "Expected 'size' to be a positive integer in Array.chunks(size) got {}",
+                size
+            )));
+        }
+
+        let chunks = self
+            .array
+            .borrow()
+            .chunks(size as usize)
+            .map(|chunk| Literal::Array(Rc::new(LoxArray::new(chunk.to_vec()))))
+            .collect();
+        Ok(Literal::Array(Rc::new(LoxArray::new(chunks))))
+    }
+
+    fn arity(&self) -> usize {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn chunks() {
+        let array = numbers(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+        let size = numbers(&[2.0]).borrow().clone();
+        let result = Chunks { array }.call(None, size).unwrap();
+        assert_eq!(result.to_string(), "[[1, 2], [3, 4], [5]]");
+    }
+
+    #[test]
+    fn chunks_zero() {
+        let array = numbers(&[1.0]);
+        let size = numbers(&[0.0]).borrow().clone();
+        assert!(Chunks { array }.call(None, size).is_err());
+    }
+
+    #[test]
+    fn chunks_not_integer() {
+        for size in [f64::NAN, 1.5, f64::INFINITY, -1.0] {
+            let array = numbers(&[1.0, 2.0]);
+            let size = numbers(&[size]).borrow().clone();
+            assert!(Chunks { array }.call(None, size).is_err());
+        }
+    }
+}
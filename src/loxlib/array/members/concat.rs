@@ -0,0 +1,56 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{array::{loxarray::LoxArray, ArrayData}, loxnatives::LoxNative},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Concat {
+    array: ArrayData,
+}
+
+impl Concat {
+    pub fn new(array: ArrayData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("concat", Rc::new(Self { array }), true)))
+    }
+}
+
+impl LoxCallable for Concat {
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let other = match args.first().expect("Array.concat array was null") {
+            Literal::Array(other) => other.array.borrow().clone(),
+            other => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'Array' for 'other' in Array.concat(other) got '{}'",
+                    other.get_typename()
+                )))
+            }
+        };
+
+        let mut elems = self.array.borrow().clone();
+        elems.extend(other);
+        Ok(Literal::Array(Rc::new(LoxArray::new(elems))))
+    }
+
+    fn arity(&self) -> usize {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn concat() {
+        let array = numbers(&[1.0, 2.0]);
+        let other = Literal::Array(Rc::new(LoxArray::new(numbers(&[3.0]).borrow().clone())));
+        let result = Concat { array: array.clone() }.call(None, vec![other]).unwrap();
+        assert_eq!(result.to_string(), "[1, 2, 3]");
+        assert_eq!(show(&array), "[1, 2]");
+    }
+}
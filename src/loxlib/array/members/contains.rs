@@ -0,0 +1,53 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*, lexer::literal::Literal, loxlib::{loxnatives::LoxNative, array::ArrayData},
+    runtime::callable::LoxCallable, runtime::interpreter::Interpreter,
+};
+
+pub struct Contains {
+    array: ArrayData
+}
+
+impl Contains {
+    pub fn new(array: ArrayData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("contains", Rc::new(Self { array }), true)))
+    }
+}
+
+impl LoxCallable for Contains {
+    fn call(&self, interpreter: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let interpreter = interpreter.expect("Interpreter was not provided for Array.contains");
+        let val = args.first().expect("Array.contains value was null");
+
+        // `__eq__` may touch the array, so compare against a snapshot
+        let elems = self.array.borrow().clone();
+        for el in elems.iter() {
+            if interpreter.is_equal(el, val)? {
+                return Ok(Literal::Bool(true));
+            }
+        }
+
+        Ok(Literal::Bool(false))
+    }
+
+    fn arity(&self) -> usize {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn contains() {
+        let interpreter = Interpreter::new();
+        let contains = Contains { array: numbers(&[1.0, 2.0]) };
+        let found = contains.call(Some(&interpreter), numbers(&[2.0]).borrow().clone()).unwrap();
+        let missing = contains.call(Some(&interpreter), strings(&["2"]).borrow().clone()).unwrap();
+        assert_eq!(found.to_string(), "true");
+        assert_eq!(missing.to_string(), "false");
+    }
+}
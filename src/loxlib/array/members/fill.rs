@@ -0,0 +1,47 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{array::ArrayData, loxnatives::LoxNative},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Fill {
+    array: ArrayData,
+}
+
+impl Fill {
+    pub fn new(array: ArrayData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("fill", Rc::new(Self { array }), true)))
+    }
+}
+
+impl LoxCallable for Fill {
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let val = args.first().expect("Array.fill value was null");
+        for el in self.array.borrow_mut().iter_mut() {
+            *el = val.dup();
+        }
+        Ok(Literal::None)
+    }
+
+    fn arity(&self) -> usize {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn fill() {
+        let array = numbers(&[1.0, 2.0, 3.0]);
+        let val = numbers(&[0.0]).borrow()[0].dup();
+        Fill { array: array.clone() }.call(None, vec![val]).unwrap();
+        assert_eq!(show(&array), "[0, 0, 0]");
+    }
+}
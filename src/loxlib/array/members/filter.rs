@@ -0,0 +1,62 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{array::{loxarray::LoxArray, ArrayData}, loxnatives::LoxNative},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Filter {
+    array: ArrayData,
+}
+
+impl Filter {
+    pub fn new(array: ArrayData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("filter", Rc::new(Self { array }), true)))
+    }
+}
+
+impl LoxCallable for Filter {
+    fn call(&self, interpreter: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let interpreter = interpreter.expect("Interpreter was not provided for Array.filter");
+        let callback = args.first().expect("Array.filter callback was null");
+
+        let elems = self.array.borrow().clone();
+        let mut kept = Vec::new();
+        for el in elems.into_iter() {
+            if interpreter.is_truthy(&interpreter.call_value(callback, vec![el.dup()])?) {
+                kept.push(el);
+            }
+        }
+        Ok(Literal::Array(Rc::new(LoxArray::new(kept))))
+    }
+
+    fn arity(&self) -> usize {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn filter() {
+        let (interpreter, f) = callback("let f = lm(x) => x > 1;");
+        let filter = Filter { array: numbers(&[3.0, 1.0, 2.0, 0.0]) };
+        let kept = filter.call(Some(&interpreter), vec![f]).unwrap();
+        assert_eq!(kept.to_string(), "[3, 2]");
+    }
+
+    #[test]
+    fn filter_truthiness() {
+        let (interpreter, f) = callback("let f = lm(x) => x;");
+        let filter = Filter { array: strings(&["a", ""]) };
+        filter.array.borrow_mut().push(Literal::None);
+        let kept = filter.call(Some(&interpreter), vec![f]).unwrap();
+        assert_eq!(kept.to_string(), r#"["a", ""]"#);
+    }
+}
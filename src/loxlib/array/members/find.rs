@@ -0,0 +1,53 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{array::ArrayData, loxnatives::LoxNative},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Find {
+    array: ArrayData,
+}
+
+impl Find {
+    pub fn new(array: ArrayData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("find", Rc::new(Self { array }), true)))
+    }
+}
+
+impl LoxCallable for Find {
+    fn call(&self, interpreter: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let interpreter = interpreter.expect("Interpreter was not provided for Array.find");
+        let callback = args.first().expect("Array.find callback was null");
+
+        let elems = self.array.borrow().clone();
+        for el in elems.into_iter() {
+            if interpreter.is_truthy(&interpreter.call_value(callback, vec![el.dup()])?) {
+                return Ok(el);
+            }
+        }
+        Ok(Literal::None)
+    }
+
+    fn arity(&self) -> usize {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn find() {
+        let (interpreter, f) = callback("let f = lm(x) => x > 1;");
+        let find = Find { array: numbers(&[1.0, 5.0, 7.0]) };
+        assert_eq!(find.call(Some(&interpreter), vec![f.dup()]).unwrap().unwrap_number(), 5.0);
+        let none = Find { array: numbers(&[0.0]) };
+        assert_eq!(none.call(Some(&interpreter), vec![f]).unwrap(), Literal::None);
+    }
+}
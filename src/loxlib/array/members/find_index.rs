@@ -0,0 +1,53 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{array::ArrayData, loxnatives::LoxNative, number::loxnumber::LoxNumber},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct FindIndex {
+    array: ArrayData,
+}
+
+impl FindIndex {
+    pub fn new(array: ArrayData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("find_index", Rc::new(Self { array }), true)))
+    }
+}
+
+impl LoxCallable for FindIndex {
+    fn call(&self, interpreter: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let interpreter = interpreter.expect("Interpreter was not provided for Array.find_index");
+        let callback = args.first().expect("Array.find_index callback was null");
+
+        let elems = self.array.borrow().clone();
+        for (i, el) in elems.into_iter().enumerate() {
+            if interpreter.is_truthy(&interpreter.call_value(callback, vec![el])?) {
+                return Ok(Literal::Number(Rc::new(LoxNumber::new(i as f64))));
+            }
+        }
+        Ok(Literal::Number(Rc::new(LoxNumber::new(-1.0))))
+    }
+
+    fn arity(&self) -> usize {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn find_index() {
+        let (interpreter, f) = callback("let f = lm(x) => x == \"b\";");
+        let found = FindIndex { array: strings(&["a", "b", "b"]) };
+        let missing = FindIndex { array: strings(&["a"]) };
+        assert_eq!(found.call(Some(&interpreter), vec![f.dup()]).unwrap().unwrap_number(), 1.0);
+        assert_eq!(missing.call(Some(&interpreter), vec![f]).unwrap().unwrap_number(), -1.0);
+    }
+}
@@ -0,0 +1,56 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{array::{loxarray::LoxArray, ArrayData}, loxnatives::LoxNative},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct FlatMap {
+    array: ArrayData,
+}
+
+impl FlatMap {
+    pub fn new(array: ArrayData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("flat_map", Rc::new(Self { array }), true)))
+    }
+}
+
+impl LoxCallable for FlatMap {
+    fn call(&self, interpreter: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let interpreter = interpreter.expect("Interpreter was not provided for Array.flat_map");
+        let callback = args.first().expect("Array.flat_map callback was null");
+
+        let elems = self.array.borrow().clone();
+        let mut flat = Vec::new();
+        for el in elems.into_iter() {
+            // Only one level is flattened, other values are kept as they are
+            match interpreter.call_value(callback, vec![el])? {
+                Literal::Array(arr) => flat.extend(arr.array.borrow().iter().map(|x| x.dup())),
+                val => flat.push(val),
+            }
+        }
+        Ok(Literal::Array(Rc::new(LoxArray::new(flat))))
+    }
+
+    fn arity(&self) -> usize {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn flat_map() {
+        let (interpreter, f) = callback("let f = lm(x) => x > 1 ? [x, [x]] : x;");
+        let flat_map = FlatMap { array: numbers(&[1.0, 2.0]) };
+        let flat = flat_map.call(Some(&interpreter), vec![f]).unwrap();
+        // Only one level is flattened
+        assert_eq!(flat.to_string(), "[1, 2, [2]]");
+    }
+}
@@ -0,0 +1,52 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{array::ArrayData, loxnatives::LoxNative},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct ForEach {
+    array: ArrayData,
+}
+
+impl ForEach {
+    pub fn new(array: ArrayData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("for_each", Rc::new(Self { array }), true)))
+    }
+}
+
+impl LoxCallable for ForEach {
+    fn call(&self, interpreter: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let interpreter = interpreter.expect("Interpreter was not provided for Array.for_each");
+        let callback = args.first().expect("Array.for_each callback was null");
+
+        let elems = self.array.borrow().clone();
+        for el in elems.into_iter() {
+            interpreter.call_value(callback, vec![el])?;
+        }
+        Ok(Literal::None)
+    }
+
+    fn arity(&self) -> usize {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use crate::runtime::tests::global;
+    use super::*;
+
+    #[test]
+    fn for_each() {
+        let (interpreter, f) = callback("let seen = []; let f = lm(x) => seen.push(x + 1);");
+        let for_each = ForEach { array: numbers(&[1.0, 2.0]) };
+        let result = for_each.call(Some(&interpreter), vec![f]).unwrap();
+        assert_eq!(result, Literal::None);
+        assert_eq!(global(&interpreter, "seen").to_string(), "[2, 3]");
+    }
+}
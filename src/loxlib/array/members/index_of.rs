@@ -0,0 +1,52 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*, lexer::literal::Literal, loxlib::{loxnatives::LoxNative, array::ArrayData, number::loxnumber::LoxNumber},
+    runtime::callable::LoxCallable, runtime::interpreter::Interpreter,
+};
+
+pub struct IndexOf {
+    array: ArrayData
+}
+
+impl IndexOf {
+    pub fn new(array: ArrayData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("index_of", Rc::new(Self { array }), true)))
+    }
+}
+
+impl LoxCallable for IndexOf {
+    fn call(&self, interpreter: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let interpreter = interpreter.expect("Interpreter was not provided for Array.index_of");
+        let val = args.first().expect("Array.index_of value was null");
+
+        let elems = self.array.borrow().clone();
+        for (index, el) in elems.iter().enumerate() {
+            if interpreter.is_equal(el, val)? {
+                return Ok(Literal::Number(Rc::new(LoxNumber::new(index as f64))));
+            }
+        }
+
+        Ok(Literal::Number(Rc::new(LoxNumber::new(-1.0))))
+    }
+
+    fn arity(&self) -> usize {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn index_of() {
+        let interpreter = Interpreter::new();
+        let index_of = IndexOf { array: numbers(&[1.0, 2.0, 2.0]) };
+        let found = index_of.call(Some(&interpreter), numbers(&[2.0]).borrow().clone()).unwrap();
+        let missing = index_of.call(Some(&interpreter), numbers(&[5.0]).borrow().clone()).unwrap();
+        assert_eq!(found.unwrap_number(), 1.0);
+        assert_eq!(missing.unwrap_number(), -1.0);
+    }
+}
@@ -0,0 +1,67 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{array::ArrayData, loxnatives::LoxNative, string::loxstring::LoxString},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Join {
+    array: ArrayData,
+}
+
+impl Join {
+    pub fn new(array: ArrayData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("join", Rc::new(Self { array }), true)))
+    }
+}
+
+impl LoxCallable for Join {
+    fn call(&self, interpreter: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let interpreter = interpreter.expect("Interpreter was not provided for Array.join");
+        let sep = args.first().expect("Array.join separator was null");
+        if sep.get_typename() != "String" {
+            return Err(LoxResult::Message(format!(
+                "Expected type 'String' for 'sep' in Array.join(sep) got '{}'",
+                sep.get_typename()
+            )));
+        }
+
+        let elems = self.array.borrow().clone();
+        let mut parts = Vec::with_capacity(elems.len());
+        for el in elems.iter() {
+            parts.push(interpreter.stringify(el)?);
+        }
+        Ok(Literal::Str(Rc::new(LoxString::new(parts.join(&sep.unwrap_str())))))
+    }
+
+    fn arity(&self) -> usize {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn join() {
+        let interpreter = Interpreter::new();
+        let array = numbers(&[1.0, 2.0, 3.0]);
+        let sep = strings(&[", "]).borrow()[0].dup();
+        let result = Join { array }.call(Some(&interpreter), vec![sep]).unwrap();
+        assert_eq!(result.unwrap_str(), "1, 2, 3");
+    }
+
+    #[test]
+    fn join_strings() {
+        let interpreter = Interpreter::new();
+        let array = strings(&["a", "b"]);
+        let sep = strings(&["-"]).borrow()[0].dup();
+        let result = Join { array }.call(Some(&interpreter), vec![sep]).unwrap();
+        assert_eq!(result.unwrap_str(), "a-b");
+    }
+}
@@ -0,0 +1,60 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{array::{loxarray::LoxArray, ArrayData}, loxnatives::LoxNative},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Map {
+    array: ArrayData,
+}
+
+impl Map {
+    pub fn new(array: ArrayData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("map", Rc::new(Self { array }), true)))
+    }
+}
+
+impl LoxCallable for Map {
+    fn call(&self, interpreter: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let interpreter = interpreter.expect("Interpreter was not provided for Array.map");
+        let callback = args.first().expect("Array.map callback was null");
+
+        // The callback may modify the array, so iterate over a snapshot
+        let elems = self.array.borrow().clone();
+        let mut mapped = Vec::with_capacity(elems.len());
+        for el in elems.into_iter() {
+            mapped.push(interpreter.call_value(callback, vec![el])?);
+        }
+        Ok(Literal::Array(Rc::new(LoxArray::new(mapped))))
+    }
+
+    fn arity(&self) -> usize {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn map() {
+        let (interpreter, f) = callback("let f = lm(x) => x * 2;");
+        let map = Map { array: numbers(&[1.0, 2.5, -3.0]) };
+        let mapped = map.call(Some(&interpreter), vec![f]).unwrap();
+        assert_eq!(mapped.to_string(), "[2, 5, -6]");
+        assert_eq!(show(&map.array), "[1, 2.5, -3]");
+    }
+
+    #[test]
+    fn map_callback_error() {
+        let (interpreter, f) = callback("let f = lm(x, y) => x;");
+        let map = Map { array: numbers(&[1.0]) };
+        assert!(map.call(Some(&interpreter), vec![f]).is_err());
+    }
+}
@@ -5,3 +5,71 @@ pub mod delete;
 pub mod insert;
 pub mod len;
 pub mod replace;
+pub mod contains;
+pub mod index_of;
+pub mod map;
+pub mod filter;
+pub mod reduce;
+pub mod for_each;
+pub mod find;
+pub mod find_index;
+pub mod any;
+pub mod all;
+pub mod flat_map;
+pub mod sort;
+pub mod sort_by;
+pub mod reverse;
+pub mod join;
+pub mod concat;
+pub mod slice;
+pub mod fill;
+pub mod unique;
+pub mod zip;
+pub mod chunks;
+pub mod range;
+
+#[cfg(test)]
+pub mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use crate::{
+        lexer::literal::Literal,
+        loxlib::{
+            array::{loxarray::LoxArray, ArrayData},
+            number::loxnumber::LoxNumber,
+            string::loxstring::LoxString,
+        },
+        runtime::{
+            interpreter::Interpreter,
+            tests::{global, run},
+        },
+    };
+
+    pub fn numbers(values: &[f64]) -> ArrayData {
+        let elems = values
+            .iter()
+            .map(|n| Literal::Number(Rc::new(LoxNumber::new(*n))))
+            .collect();
+        Rc::new(RefCell::new(elems))
+    }
+
+    pub fn strings(values: &[&str]) -> ArrayData {
+        let elems = values
+            .iter()
+            .map(|s| Literal::Str(Rc::new(LoxString::new(s.to_string()))))
+            .collect();
+        Rc::new(RefCell::new(elems))
+    }
+
+    pub fn show(array: &ArrayData) -> String {
+        Literal::Array(Rc::new(LoxArray::new(array.borrow().clone()))).to_string()
+    }
+
+    // Runs `source`, which declares the callback `f`, and returns the
+    // interpreter to call it with.
+    pub fn callback(source: &str) -> (Interpreter, Literal) {
+        let interpreter = run(source).unwrap_or_else(|_| panic!("failed {source}"));
+        let f = global(&interpreter, "f");
+        (interpreter, f)
+    }
+}
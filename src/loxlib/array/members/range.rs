@@ -0,0 +1,92 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{
+        array::loxarray::LoxArray, loxnatives::LoxNative, number::loxnumber::LoxNumber, MAX_LENGTH,
+    },
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Range;
+
+impl Range {
+    pub fn new() -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("range", Rc::new(Self {}), true)))
+    }
+}
+
+impl LoxCallable for Range {
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let n = args.first().expect("Array.range n was null");
+        if n.get_typename() != "Number" {
+            return Err(LoxResult::Message(format!(
+                "Expected type 'Number' for 'n' in Array.range(n) got '{}'",
+                n.get_typename()
+            )));
+        }
+
+        // Also rejects NaN, infinity and fractions, negative numbers give an empty array
+        let n = n.unwrap_number();
+        if n.is_nan() || n.fract() != 0.0 {
+            return Err(LoxResult::Message(format!(
+                "Expected 'n' to be an integer in Array.range(n) got {}",
+                n
+            )));
+        }
+        if n > MAX_LENGTH as f64 {
+            return Err(LoxResult::Message(format!(
+                "Expected 'n' to be at most {} in Array.range(n) got {}",
+                MAX_LENGTH, n
+            )));
+        }
+
+        let range = (0..n.max(0.0) as usize)
+            .map(|i| Literal::Number(Rc::new(LoxNumber::new(i as f64))))
+            .collect();
+        Ok(Literal::Array(Rc::new(LoxArray::new(range))))
+    }
+
+    fn arity(&self) -> usize {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn range() {
+        let result = Range.call(None, numbers(&[4.0]).borrow().clone()).unwrap();
+        assert_eq!(result.to_string(), "[0, 1, 2, 3]");
+    }
+
+    #[test]
+    fn range_negative() {
+        let result = Range.call(None, numbers(&[-2.0]).borrow().clone()).unwrap();
+        assert_eq!(result.to_string(), "[]");
+    }
+
+    #[test]
+    fn range_invalid() {
+        for n in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 2.5, -0.5] {
+            match Range.call(None, numbers(&[n]).borrow().clone()) {
+                Err(LoxResult::Message(msg)) => assert!(msg.contains("an integer"), "{}", msg),
+                _ => panic!("Array.range accepted {}", n),
+            }
+        }
+        assert!(Range.call(None, strings(&["3"]).borrow().clone()).is_err());
+    }
+
+    #[test]
+    fn range_too_large() {
+        let result = Range.call(None, numbers(&[MAX_LENGTH as f64 + 1.0]).borrow().clone());
+        assert!(matches!(result, Err(LoxResult::Message(msg)) if msg.contains("at most")));
+        let result = Range.call(None, numbers(&[1e300]).borrow().clone());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,78 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{array::ArrayData, loxnatives::LoxNative},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Reduce {
+    array: ArrayData,
+}
+
+impl Reduce {
+    pub fn new(array: ArrayData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("reduce", Rc::new(Self { array }), false)))
+    }
+}
+
+impl LoxCallable for Reduce {
+    fn call(&self, interpreter: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let interpreter = interpreter.expect("Interpreter was not provided for Array.reduce");
+        if args.is_empty() || args.len() > 2 {
+            return Err(LoxResult::Message(format!(
+                "Expected 1 to 2 arguments but got {} for 'reduce'",
+                args.len()
+            )));
+        }
+        let mut args = args.into_iter();
+        let callback = args.next().unwrap();
+
+        let mut elems = self.array.borrow().clone().into_iter();
+        // Without an initial value the first element is used
+        let mut acc = match args.next().or_else(|| elems.next()) {
+            Some(acc) => acc,
+            None => {
+                return Err(LoxResult::Message(
+                    "Cannot reduce an empty array without an initial value".to_string(),
+                ))
+            }
+        };
+        for el in elems {
+            acc = interpreter.call_value(&callback, vec![acc, el])?;
+        }
+        Ok(acc)
+    }
+
+    fn arity(&self) -> usize {
+        2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn reduce() {
+        let (interpreter, f) = callback("let f = lm(acc, x) => acc + x;");
+        let reduce = Reduce { array: numbers(&[1.0, 2.0, 3.0]) };
+        let sum = reduce.call(Some(&interpreter), vec![f.dup()]).unwrap();
+        let initial = numbers(&[10.0]).borrow()[0].dup();
+        let with_initial = reduce.call(Some(&interpreter), vec![f, initial]).unwrap();
+        assert_eq!(sum.unwrap_number(), 6.0);
+        assert_eq!(with_initial.unwrap_number(), 16.0);
+    }
+
+    #[test]
+    fn reduce_empty() {
+        let (interpreter, f) = callback("let f = lm(acc, x) => acc + x;");
+        let reduce = Reduce { array: numbers(&[]) };
+        assert!(reduce.call(Some(&interpreter), vec![f.dup()]).is_err());
+        let initial = strings(&["init"]).borrow()[0].dup();
+        assert_eq!(reduce.call(Some(&interpreter), vec![f, initial]).unwrap().repr(), r#""init""#);
+    }
+}
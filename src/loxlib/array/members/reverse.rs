@@ -0,0 +1,43 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{array::ArrayData, loxnatives::LoxNative},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Reverse {
+    array: ArrayData,
+}
+
+impl Reverse {
+    pub fn new(array: ArrayData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("reverse", Rc::new(Self { array }), true)))
+    }
+}
+
+impl LoxCallable for Reverse {
+    fn call(&self, _: Option<&Interpreter>, _: Vec<Literal>) -> Result<Literal, LoxResult> {
+        self.array.borrow_mut().reverse();
+        Ok(Literal::None)
+    }
+
+    fn arity(&self) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn reverse() {
+        let array = numbers(&[1.0, 2.0, 3.0]);
+        Reverse { array: array.clone() }.call(None, Vec::new()).unwrap();
+        assert_eq!(show(&array), "[3, 2, 1]");
+    }
+}
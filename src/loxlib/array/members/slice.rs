@@ -0,0 +1,66 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{array::{loxarray::LoxArray, ArrayData}, loxnatives::LoxNative},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Slice {
+    array: ArrayData,
+}
+
+impl Slice {
+    pub fn new(array: ArrayData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("slice", Rc::new(Self { array }), true)))
+    }
+}
+
+impl LoxCallable for Slice {
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let start = args.first().expect("Array.slice start was null");
+        let end = args.get(1).expect("Array.slice end was null");
+        for (name, arg) in [("start", start), ("end", end)] {
+            if arg.get_typename() != "Number" {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'Number' for {} in Array.slice(start, end) got '{}'",
+                    name,
+                    arg.get_typename()
+                )));
+            }
+        }
+
+        let elems = self.array.borrow();
+        let end = (end.unwrap_number() as usize).min(elems.len());
+        let start = (start.unwrap_number() as usize).min(end);
+        Ok(Literal::Array(Rc::new(LoxArray::new(elems[start..end].to_vec()))))
+    }
+
+    fn arity(&self) -> usize {
+        2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn slice() {
+        let array = numbers(&[0.0, 1.0, 2.0, 3.0]);
+        let args = numbers(&[1.0, 3.0]).borrow().clone();
+        let result = Slice { array }.call(None, args).unwrap();
+        assert_eq!(result.to_string(), "[1, 2]");
+    }
+
+    #[test]
+    fn slice_out_of_range() {
+        let array = numbers(&[0.0, 1.0]);
+        let args = numbers(&[3.0, 10.0]).borrow().clone();
+        let result = Slice { array }.call(None, args).unwrap();
+        assert_eq!(result.to_string(), "[]");
+    }
+}
@@ -0,0 +1,95 @@
+use std::{cmp::Ordering, rc::Rc};
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{array::ArrayData, loxnatives::LoxNative},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Sort {
+    array: ArrayData,
+}
+
+impl Sort {
+    pub fn new(array: ArrayData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("sort", Rc::new(Self { array }), true)))
+    }
+}
+
+impl LoxCallable for Sort {
+    fn call(&self, _: Option<&Interpreter>, _: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let mut elems = self.array.borrow().clone();
+        // Checked up front, mixed types would make the order inconsistent
+        if let Some(first) = elems.first() {
+            for el in elems.iter() {
+                let comparable = matches!(
+                    (first, el),
+                    (Literal::Number(_), Literal::Number(_)) | (Literal::Str(_), Literal::Str(_))
+                );
+                if !comparable {
+                    return Err(LoxResult::Message(format!(
+                        "Cannot compare '{}' with '{}' in Array.sort()",
+                        first.get_typename(),
+                        el.get_typename()
+                    )));
+                }
+            }
+        }
+        // `sort_by` is stable, equal elements keep their order
+        elems.sort_by(|a, b| match (a, b) {
+            (Literal::Number(x), Literal::Number(y)) => x.num.borrow().total_cmp(&y.num.borrow()),
+            (Literal::Str(x), Literal::Str(y)) => x.string.borrow().cmp(&y.string.borrow()),
+            _ => Ordering::Equal,
+        });
+        *self.array.borrow_mut() = elems;
+        Ok(Literal::None)
+    }
+
+    fn arity(&self) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn sort_numbers() {
+        let array = numbers(&[3.0, -1.0, 2.5, 0.0]);
+        Sort { array: array.clone() }.call(None, Vec::new()).unwrap();
+        assert_eq!(show(&array), "[-1, 0, 2.5, 3]");
+    }
+
+    #[test]
+    fn sort_strings() {
+        let array = strings(&["pear", "apple", "fig"]);
+        Sort { array: array.clone() }.call(None, Vec::new()).unwrap();
+        assert_eq!(show(&array), r#"["apple", "fig", "pear"]"#);
+    }
+
+    #[test]
+    fn sort_mixed() {
+        let array = numbers(&[1.0]);
+        array.borrow_mut().push(strings(&["a"]).borrow()[0].dup());
+        assert!(Sort { array }.call(None, Vec::new()).is_err());
+
+        let array = numbers(&[]);
+        array.borrow_mut().extend([Literal::Bool(true), Literal::Bool(false)]);
+        assert!(Sort { array: array.clone() }.call(None, Vec::new()).is_err());
+        assert_eq!(show(&array), "[true, false]");
+    }
+
+    #[test]
+    fn sort_mixed_large() {
+        // Many mixed values once made the std sort panic on the inconsistent order
+        let array = numbers(&(0..100).map(|n| n as f64).collect::<Vec<f64>>());
+        for i in (0..100).step_by(3) {
+            array.borrow_mut()[i] = strings(&["a"]).borrow()[0].dup();
+        }
+        assert!(Sort { array }.call(None, Vec::new()).is_err());
+    }
+}
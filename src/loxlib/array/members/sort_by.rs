@@ -0,0 +1,211 @@
+use std::{cmp::Ordering, rc::Rc};
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{array::ArrayData, loxnatives::LoxNative},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct SortBy {
+    array: ArrayData,
+}
+
+impl SortBy {
+    pub fn new(array: ArrayData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("sort_by", Rc::new(Self { array }), true)))
+    }
+}
+
+impl LoxCallable for SortBy {
+    fn call(&self, interpreter: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let interpreter = interpreter.expect("Interpreter was not provided for Array.sort_by");
+        let cmp = args.first().expect("Array.sort_by comparator was null");
+
+        let elems = self.array.borrow().clone();
+        let sorted = merge_sort(elems.clone(), &mut |a, b| {
+            match interpreter.call_value(cmp, vec![a.dup(), b.dup()])? {
+                Literal::Number(n) => {
+                    let n = *n.num.borrow();
+                    if n < 0.0 {
+                        Ok(Ordering::Less)
+                    } else if n > 0.0 {
+                        Ok(Ordering::Greater)
+                    } else if n.is_nan() {
+                        Err(LoxResult::Message(
+                            "Expected comparator to return a number but got NaN in Array.sort_by(cmp)"
+                                .to_string(),
+                        ))
+                    } else {
+                        Ok(Ordering::Equal)
+                    }
+                }
+                val => Err(LoxResult::Message(format!(
+                    "Expected comparator to return 'Number' in Array.sort_by(cmp) got '{}'",
+                    val.get_typename()
+                ))),
+            }
+        })?;
+        // The sort works on a copy, writing it back would lose the comparator's changes
+        let unchanged = {
+            let array = self.array.borrow();
+            array.len() == elems.len()
+                && array.iter().zip(elems.iter()).all(|(a, b)| identical(a, b))
+        };
+        if !unchanged {
+            return Err(LoxResult::Message(
+                "Array was modified by the comparator in Array.sort_by(cmp)".to_string(),
+            ));
+        }
+        *self.array.borrow_mut() = sorted;
+        Ok(Literal::None)
+    }
+
+    fn arity(&self) -> usize {
+        1
+    }
+}
+
+// Same value, or the same object for reference types.
+fn identical(a: &Literal, b: &Literal) -> bool {
+    match (a, b) {
+        (Literal::Number(a), Literal::Number(b)) => Rc::ptr_eq(a, b),
+        (Literal::Str(a), Literal::Str(b)) => Rc::ptr_eq(a, b),
+        (Literal::Bool(a), Literal::Bool(b)) => a == b,
+        (Literal::Func(a), Literal::Func(b)) => Rc::ptr_eq(a, b),
+        (Literal::Native(a), Literal::Native(b)) => Rc::ptr_eq(a, b),
+        (Literal::Class(a), Literal::Class(b)) => Rc::ptr_eq(a, b),
+        (Literal::Instance(a), Literal::Instance(b)) => Rc::ptr_eq(a, b),
+        (Literal::Array(a), Literal::Array(b)) => Rc::ptr_eq(a, b),
+        (Literal::Enum(a), Literal::Enum(b)) => Rc::ptr_eq(a, b),
+        (Literal::Variant(a), Literal::Variant(b)) => Rc::ptr_eq(a, b),
+        (Literal::None, Literal::None) | (Literal::LiteralNone, Literal::LiteralNone) => true,
+        _ => false,
+    }
+}
+
+type Comparator<'a> = dyn FnMut(&Literal, &Literal) -> Result<Ordering, LoxResult> + 'a;
+
+// Stable merge sort, unlike `slice::sort_by` it can't panic when the Lox
+// comparator is not a consistent order, the result is then just unspecified.
+fn merge_sort(mut elems: Vec<Literal>, cmp: &mut Comparator) -> Result<Vec<Literal>, LoxResult> {
+    if elems.len() <= 1 {
+        return Ok(elems);
+    }
+    let right = elems.split_off(elems.len() / 2);
+    let left = merge_sort(elems, cmp)?;
+    let right = merge_sort(right, cmp)?;
+
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+    while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+        // Equal elements are taken from the left first to keep their order
+        if cmp(a, b)? == Ordering::Greater {
+            merged.extend(right.next());
+        } else {
+            merged.extend(left.next());
+        }
+    }
+    merged.extend(left);
+    merged.extend(right);
+    Ok(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+    use crate::{
+        loxlib::{array::loxarray::LoxArray, number::loxnumber::LoxNumber},
+        runtime::tests::{eval, run_err},
+    };
+
+    // Orders numbers from largest to smallest.
+    struct Descending;
+
+    impl LoxCallable for Descending {
+        fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+            let diff = args[1].unwrap_number() - args[0].unwrap_number();
+            Ok(Literal::Number(Rc::new(LoxNumber::new(diff))))
+        }
+
+        fn arity(&self) -> usize {
+            2
+        }
+    }
+
+    #[test]
+    fn sort_by_comparator() {
+        let interpreter = Interpreter::new();
+        let array = numbers(&[1.0, 3.0, 2.0]);
+        let cmp = Literal::Native(Rc::new(LoxNative::new("cmp", Rc::new(Descending), true)));
+        SortBy { array: array.clone() }.call(Some(&interpreter), vec![cmp]).unwrap();
+        assert_eq!(show(&array), "[3, 2, 1]");
+    }
+
+    #[test]
+    fn sort_by_not_callable() {
+        let interpreter = Interpreter::new();
+        let array = numbers(&[1.0, 2.0]);
+        let result = SortBy { array }.call(Some(&interpreter), numbers(&[1.0]).borrow().clone());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn sort_by_is_stable() {
+        let (interpreter, f) = callback("let f = lm(a, b) => a[0] - b[0];");
+        let array = numbers(&[]);
+        for (key, tag) in [(2.0, "a"), (1.0, "b"), (2.0, "c"), (1.0, "d"), (0.0, "e")] {
+            let pair = numbers(&[key]);
+            pair.borrow_mut().push(strings(&[tag]).borrow()[0].dup());
+            array.borrow_mut().push(Literal::Array(Rc::new(LoxArray::new(pair.borrow().clone()))));
+        }
+        SortBy { array: array.clone() }.call(Some(&interpreter), vec![f]).unwrap();
+        let expected = r#"[[0, "e"], [1, "b"], [1, "d"], [2, "a"], [2, "c"]]"#;
+        assert_eq!(show(&array), expected);
+    }
+
+    #[test]
+    fn sort_by_negative_zero_is_equal() {
+        let (interpreter, f) = callback("let f = lm(a, b) => -0;");
+        let array = numbers(&[3.0, 1.0, 2.0]);
+        SortBy { array: array.clone() }.call(Some(&interpreter), vec![f]).unwrap();
+        assert_eq!(show(&array), "[3, 1, 2]");
+    }
+
+    #[test]
+    fn sort_by_modified_by_comparator() {
+        let expected = "Array was modified by the comparator in Array.sort_by(cmp)".to_string();
+        for change in ["a.push(0);", "a.pop();", "a[0] = 5;"] {
+            let src = format!("let a = [3, 1, 2]; fn f(x, y) {{ {change} return x - y; }}
+                a.sort_by(f);");
+            assert_eq!(run_err(&src), LoxErrorsTypes::Runtime(expected.clone()));
+        }
+        let src = "let a = [3, 1, 2]; fn f(x, y) { a[0] = a[0]; return x - y; } a.sort_by(f);";
+        assert_eq!(eval(src, "a"), "[1, 2, 3]");
+    }
+
+    #[test]
+    fn sort_by_nan() {
+        let (interpreter, f) = callback("let f = lm(a, b) => 0 / 0;");
+        let array = numbers(&[2.0, 1.0]);
+        assert!(SortBy { array: array.clone() }.call(Some(&interpreter), vec![f]).is_err());
+        assert_eq!(show(&array), "[2, 1]");
+    }
+
+    #[test]
+    fn sort_by_inconsistent_comparator() {
+        let source = "let n = 0; let f = lm(a, b) { n += 1; return n % 3 - 1; };";
+        let (interpreter, f) = callback(source);
+        let values: Vec<f64> = (0..500).map(|n| n as f64).collect();
+        let array = numbers(&values);
+        SortBy { array: array.clone() }.call(Some(&interpreter), vec![f]).unwrap();
+
+        // Whatever the order, no element is lost
+        let mut sorted: Vec<f64> = array.borrow().iter().map(|n| n.unwrap_number()).collect();
+        sorted.sort_by(f64::total_cmp);
+        assert_eq!(sorted, values);
+    }
+}
@@ -0,0 +1,59 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{array::{loxarray::LoxArray, ArrayData}, loxnatives::LoxNative},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Unique {
+    array: ArrayData,
+}
+
+impl Unique {
+    pub fn new(array: ArrayData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("unique", Rc::new(Self { array }), true)))
+    }
+}
+
+impl LoxCallable for Unique {
+    fn call(&self, interpreter: Option<&Interpreter>, _: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let interpreter = interpreter.expect("Interpreter was not provided for Array.unique");
+        let elems = self.array.borrow().clone();
+        let mut unique: Vec<Literal> = Vec::new();
+        // Equality goes through `__eq__`, so the first of equal elements is kept
+        for el in elems.into_iter() {
+            let mut seen = false;
+            for u in unique.iter() {
+                if interpreter.is_equal(u, &el)? {
+                    seen = true;
+                    break;
+                }
+            }
+            if !seen {
+                unique.push(el);
+            }
+        }
+        Ok(Literal::Array(Rc::new(LoxArray::new(unique))))
+    }
+
+    fn arity(&self) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn unique() {
+        let interpreter = Interpreter::new();
+        let array = numbers(&[1.0, 2.0, 1.0, 3.0, 2.0]);
+        let result = Unique { array }.call(Some(&interpreter), Vec::new()).unwrap();
+        assert_eq!(result.to_string(), "[1, 2, 3]");
+    }
+}
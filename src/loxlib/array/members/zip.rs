@@ -0,0 +1,61 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{array::{loxarray::LoxArray, ArrayData}, loxnatives::LoxNative},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Zip {
+    array: ArrayData,
+}
+
+impl Zip {
+    pub fn new(array: ArrayData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("zip", Rc::new(Self { array }), true)))
+    }
+}
+
+impl LoxCallable for Zip {
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let other = match args.first().expect("Array.zip array was null") {
+            Literal::Array(other) => other.array.borrow().clone(),
+            other => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'Array' for 'other' in Array.zip(other) got '{}'",
+                    other.get_typename()
+                )))
+            }
+        };
+
+        // Stops at the end of the shorter array
+        let pairs = self
+            .array
+            .borrow()
+            .iter()
+            .zip(other)
+            .map(|(a, b)| Literal::Array(Rc::new(LoxArray::new(vec![a.dup(), b]))))
+            .collect();
+        Ok(Literal::Array(Rc::new(LoxArray::new(pairs))))
+    }
+
+    fn arity(&self) -> usize {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn zip() {
+        let array = numbers(&[1.0, 2.0, 3.0]);
+        let other = Literal::Array(Rc::new(LoxArray::new(strings(&["a", "b"]).borrow().clone())));
+        let result = Zip { array }.call(None, vec![other]).unwrap();
+        assert_eq!(result.to_string(), r#"[[1, "a"], [2, "b"]]"#);
+    }
+}
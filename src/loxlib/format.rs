@@ -0,0 +1,366 @@
+use std::{iter::Peekable, rc::Rc, str::Chars};
+
+use crate::{
+    error::*, lexer::literal::Literal, runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+use super::string::loxstring::LoxString;
+
+pub struct Format;
+
+impl LoxCallable for Format {
+    fn arity(&self) -> usize {
+        1
+    }
+
+    fn call(
+        &self,
+        interpreter: Option<&Interpreter>,
+        args: Vec<Literal>,
+    ) -> Result<Literal, LoxResult> {
+        self.call_keywords(interpreter, args, Vec::new())
+    }
+
+    fn accepts_keywords(&self) -> bool {
+        true
+    }
+
+    fn call_keywords(
+        &self,
+        interpreter: Option<&Interpreter>,
+        args: Vec<Literal>,
+        keywords: Vec<(String, Literal)>,
+    ) -> Result<Literal, LoxResult> {
+        let interpreter = interpreter.expect("Interpreter was not provided for format");
+        let template = match args.first() {
+            Some(Literal::Str(template)) => template.string.borrow().to_string(),
+            Some(arg) => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'String' for 'template' in format(template, ...) got '{}'",
+                    arg.get_typename()
+                )))
+            }
+            None => {
+                return Err(LoxResult::Message(
+                    "Expected at least 1 arguments but got 0 for 'format'".to_string(),
+                ))
+            }
+        };
+        let string = format_string(interpreter, &template, &args[1..], &keywords)?;
+        Ok(Literal::Str(Rc::new(LoxString::new(string))))
+    }
+}
+
+// Largest width or precision a placeholder accepts.
+const MAX_SPEC_NUMBER: usize = 1000;
+
+// `{[arg][:spec]}` where spec is `[[fill]align][0][width][.precision][x|X|b]`.
+#[derive(Default)]
+struct Spec {
+    fill: Option<char>,
+    align: Option<char>,
+    zero: bool,
+    width: usize,
+    precision: Option<usize>,
+    kind: Option<char>,
+}
+
+impl Spec {
+    fn parse(spec: &str) -> Option<Self> {
+        let chars: Vec<char> = spec.chars().collect();
+        let mut spec = Spec::default();
+        let mut i = 0;
+        if chars.len() > 1 && "<>^".contains(chars[1]) {
+            spec.fill = Some(chars[0]);
+            spec.align = Some(chars[1]);
+            i = 2;
+        } else if !chars.is_empty() && "<>^".contains(chars[0]) {
+            spec.align = Some(chars[0]);
+            i = 1;
+        }
+        if chars.get(i) == Some(&'0') {
+            spec.zero = true;
+            i += 1;
+        }
+        if chars.get(i).is_some_and(|c| c.is_ascii_digit()) {
+            spec.width = Spec::number(&chars, &mut i)?;
+        }
+        if chars.get(i) == Some(&'.') {
+            i += 1;
+            spec.precision = Some(Spec::number(&chars, &mut i)?);
+        }
+        if let Some(kind) = chars.get(i).filter(|c| "xXb".contains(**c)) {
+            spec.kind = Some(*kind);
+            i += 1;
+        }
+        if i != chars.len() {
+            return None;
+        }
+        Some(spec)
+    }
+
+    // `None` when there are no digits or the number is too large.
+    fn number(chars: &[char], i: &mut usize) -> Option<usize> {
+        let start = *i;
+        while chars.get(*i).is_some_and(|c| c.is_ascii_digit()) {
+            *i += 1;
+        }
+        let number: usize = chars[start..*i].iter().collect::<String>().parse().ok()?;
+        (number <= MAX_SPEC_NUMBER).then_some(number)
+    }
+
+    fn apply(&self, interpreter: &Interpreter, value: &Literal) -> Result<String, LoxResult> {
+        let is_number = matches!(value, Literal::Number(_));
+        let body = match (value, self.kind) {
+            (Literal::Number(num), Some(kind)) => {
+                let num = *num.num.borrow();
+                if num.fract() != 0.0 || !num.is_finite() {
+                    return Err(LoxResult::Message(format!(
+                        "Expected an integer to format as '{}' but got '{}'",
+                        kind,
+                        interpreter.stringify(value)?
+                    )));
+                }
+                let sign = if num < 0.0 { "-" } else { "" };
+                let abs = num.abs() as u64;
+                match kind {
+                    'x' => format!("{sign}{abs:x}"),
+                    'X' => format!("{sign}{abs:X}"),
+                    _ => format!("{sign}{abs:b}"),
+                }
+            }
+            (_, Some(kind)) => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'Number' to format as '{}' but got '{}'",
+                    kind,
+                    value.get_typename()
+                )))
+            }
+            (Literal::Number(num), None) => match self.precision {
+                Some(precision) => format!("{:.*}", precision, *num.num.borrow()),
+                None => interpreter.stringify(value)?,
+            },
+            (_, None) => {
+                let string = interpreter.stringify(value)?;
+                match self.precision {
+                    Some(precision) => string.chars().take(precision).collect(),
+                    None => string,
+                }
+            }
+        };
+
+        let pad = self.width.saturating_sub(body.chars().count());
+        if pad == 0 {
+            return Ok(body);
+        }
+        // Zeros go between the sign and the digits
+        if self.zero && is_number && self.align.is_none() {
+            let (sign, digits) = body.split_at(usize::from(body.starts_with('-')));
+            return Ok(format!("{}{}{}", sign, "0".repeat(pad), digits));
+        }
+        let fill = self.fill.unwrap_or(' ').to_string();
+        let align = self.align.unwrap_or(if is_number { '>' } else { '<' });
+        Ok(match align {
+            '<' => body + &fill.repeat(pad),
+            '>' => fill.repeat(pad) + &body,
+            _ => fill.repeat(pad / 2) + &body + &fill.repeat(pad - pad / 2),
+        })
+    }
+}
+
+fn placeholder(chars: &mut Peekable<Chars>) -> Result<String, LoxResult> {
+    let mut inner = String::new();
+    loop {
+        match chars.next() {
+            Some('}') => return Ok(inner),
+            Some(c) => inner.push(c),
+            None => {
+                return Err(LoxResult::Message(
+                    "Unclosed '{' in format string".to_string(),
+                ))
+            }
+        }
+    }
+}
+
+// Fills the placeholders of `template`, `{}` takes the next positional argument,
+// `{1}` the one at that position and `{name}` the keyword argument with that name.
+// Every argument has to be used by some placeholder.
+pub fn format_string(
+    interpreter: &Interpreter,
+    template: &str,
+    args: &[Literal],
+    keywords: &[(String, Literal)],
+) -> Result<String, LoxResult> {
+    let mut used = vec![false; args.len()];
+    let mut used_keywords = vec![false; keywords.len()];
+    let mut next = 0;
+    let mut out = String::new();
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                out.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                out.push('}');
+            }
+            '}' => {
+                return Err(LoxResult::Message(
+                    "Unmatched '}' in format string".to_string(),
+                ))
+            }
+            '{' => {
+                let inner = placeholder(&mut chars)?;
+                let (arg, spec) = inner.split_once(':').unwrap_or((&inner, ""));
+                let value = if arg.is_empty() || arg.chars().all(|c| c.is_ascii_digit()) {
+                    let index = if arg.is_empty() {
+                        next += 1;
+                        next - 1
+                    } else {
+                        arg.parse().unwrap_or(usize::MAX)
+                    };
+                    match args.get(index) {
+                        Some(value) => {
+                            used[index] = true;
+                            value
+                        }
+                        None => {
+                            return Err(LoxResult::Message(format!(
+                                "No argument at position {} for format string with {} arguments",
+                                index,
+                                args.len()
+                            )))
+                        }
+                    }
+                } else {
+                    match keywords.iter().position(|(name, _)| name == arg) {
+                        Some(index) => {
+                            used_keywords[index] = true;
+                            &keywords[index].1
+                        }
+                        None => {
+                            return Err(LoxResult::Message(format!(
+                                "No argument named '{}' for format string",
+                                arg
+                            )))
+                        }
+                    }
+                };
+                let spec = match Spec::parse(spec) {
+                    Some(spec) => spec,
+                    None => {
+                        return Err(LoxResult::Message(format!(
+                            "Invalid format spec '{}' in format string",
+                            spec
+                        )))
+                    }
+                };
+                out.push_str(&spec.apply(interpreter, value)?);
+            }
+            c => out.push(c),
+        }
+    }
+
+    if let Some(index) = used.iter().position(|u| !u) {
+        return Err(LoxResult::Message(format!(
+            "Argument at position {} is not used in format string",
+            index
+        )));
+    }
+    if let Some(index) = used_keywords.iter().position(|u| !u) {
+        return Err(LoxResult::Message(format!(
+            "Argument '{}' is not used in format string",
+            keywords[index].0
+        )));
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{loxlib::number::loxnumber::LoxNumber, runtime::tests::eval};
+
+    fn num(n: f64) -> Literal {
+        Literal::Number(Rc::new(LoxNumber::new(n)))
+    }
+
+    fn string(s: &str) -> Literal {
+        Literal::Str(Rc::new(LoxString::new(s.to_string())))
+    }
+
+    fn format(template: &str, args: &[Literal]) -> Result<String, LoxResult> {
+        format_string(&Interpreter::new(), template, args, &[])
+    }
+
+    #[test]
+    fn positional() {
+        let result = format("{} and {}, {0}!", &[string("a"), num(1.0)]).unwrap();
+        assert_eq!(result, "a and 1, a!");
+    }
+
+    #[test]
+    fn named() {
+        let keywords = vec![("name".to_string(), string("lox"))];
+        let result = format_string(&Interpreter::new(), "hi {name}", &[], &keywords).unwrap();
+        assert_eq!(result, "hi lox");
+    }
+
+    #[test]
+    fn width_and_alignment() {
+        let args = [num(3.14159), string("ñu")];
+        assert_eq!(format("{:>8.2}|{:<4}|", &args).unwrap(), "    3.14|ñu  |");
+        assert_eq!(format("{:*^6}", &[string("ab")]).unwrap(), "**ab**");
+        assert_eq!(format("{:5}|{:5}", &[num(42.0), string("x")]).unwrap(), "   42|x    ");
+    }
+
+    #[test]
+    fn zero_padding() {
+        assert_eq!(format("{:05}", &[num(-42.0)]).unwrap(), "-0042");
+        assert_eq!(format("{:06.2}", &[num(3.14159)]).unwrap(), "003.14");
+    }
+
+    #[test]
+    fn hex_and_binary() {
+        assert_eq!(format("{:x} {:X} {:b}", &[num(255.0), num(255.0), num(5.0)]).unwrap(), "ff FF 101");
+        assert_eq!(format("{:08b}", &[num(5.0)]).unwrap(), "00000101");
+        assert!(format("{:x}", &[num(1.5)]).is_err());
+        assert!(format("{:x}", &[string("a")]).is_err());
+    }
+
+    #[test]
+    fn spec_limits() {
+        assert_eq!(format("{:.1000}", &[string("ab")]).unwrap(), "ab");
+        assert_eq!(format("{:1000}", &[num(1.0)]).unwrap().len(), 1000);
+        assert!(format("{:.99999999}", &[num(1.0)]).is_err());
+        assert!(format("{:1001}", &[num(1.0)]).is_err());
+        assert!(format("{:99999999999999999999}", &[num(1.0)]).is_err());
+        assert!(format("{:.}", &[num(1.0)]).is_err());
+    }
+
+    #[test]
+    fn str_format() {
+        let src = "let r = [Str.format(\"{}-{x}\", 1, x: 2), \"{}!\".format(3)];";
+        assert_eq!(eval(src, "r"), r#"["1-2", "3!"]"#);
+    }
+
+    #[test]
+    fn escapes() {
+        assert_eq!(format("{{}} {}", &[num(1.0)]).unwrap(), "{} 1");
+    }
+
+    #[test]
+    fn mismatched() {
+        assert!(format("{} {}", &[num(1.0)]).is_err());
+        assert!(format("{}", &[num(1.0), num(2.0)]).is_err());
+        assert!(format("{missing}", &[]).is_err());
+        assert!(format("{", &[]).is_err());
+        assert!(format("}", &[]).is_err());
+        assert!(format("{:q}", &[num(1.0)]).is_err());
+    }
+}
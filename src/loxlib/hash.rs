@@ -0,0 +1,30 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*, lexer::literal::Literal, runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+use super::number::loxnumber::LoxNumber;
+
+// Keeps the hash exactly representable as a Number.
+const HASH_MASK: u64 = (1 << 53) - 1;
+
+pub struct HashOf;
+
+impl LoxCallable for HashOf {
+    fn arity(&self) -> usize {
+        1
+    }
+
+    fn call(
+        &self,
+        interpreter: Option<&Interpreter>,
+        args: Vec<Literal>,
+    ) -> Result<Literal, LoxResult> {
+        let interpreter = interpreter.expect("Interpreter was not provided for hash");
+        let hash = interpreter.hash_value(args.first().expect("hash value was null"))?;
+
+        Ok(Literal::Number(Rc::new(LoxNumber::new((hash & HASH_MASK) as f64))))
+    }
+}
@@ -0,0 +1,47 @@
+use std::{collections::HashMap, f64::consts, rc::Rc};
+
+use crate::{
+    lexer::literal::Literal,
+    loxlib::number::loxnumber::LoxNumber,
+    runtime::loxinstance::InstanceField,
+};
+use super::members::*;
+
+// Static fields that can't be assigned.
+pub const CONSTANTS: [&str; 3] = ["PI", "E", "INFINITY"];
+
+pub struct MathMembers;
+
+impl MathMembers {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn get_methods(&self) -> HashMap<String, Literal> {
+        let mut map: HashMap<String, Literal> = HashMap::new();
+        map.insert(String::from("init"), init::Init::new());
+        map
+    }
+
+    pub fn get_statics(&self) -> HashMap<String, Literal> {
+        let mut map: HashMap<String, Literal> = HashMap::new();
+        map.insert(String::from("sqrt"), sqrt::Sqrt::new());
+        map.insert(String::from("pow"), pow::Pow::new());
+        map.insert(String::from("sin"), sin::Sin::new());
+        map.insert(String::from("cos"), cos::Cos::new());
+        map.insert(String::from("tan"), tan::Tan::new());
+        map.insert(String::from("log"), log::Log::new());
+        map.insert(String::from("exp"), exp::Exp::new());
+        map.insert(String::from("min"), min::Min::new());
+        map.insert(String::from("max"), max::Max::new());
+        map.insert(String::from("clamp"), clamp::Clamp::new());
+        for (name, value) in CONSTANTS.iter().zip([consts::PI, consts::E, f64::INFINITY]) {
+            map.insert(name.to_string(), Literal::Number(Rc::new(LoxNumber::new(value))));
+        }
+        map
+    }
+
+    pub fn get_fields(&self) -> HashMap<String, InstanceField> {
+        HashMap::new()
+    }
+}
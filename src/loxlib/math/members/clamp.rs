@@ -0,0 +1,76 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{loxnatives::LoxNative, number::loxnumber::LoxNumber},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Clamp;
+
+impl Clamp {
+    pub fn new() -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("clamp", Rc::new(Self {}), true)))
+    }
+}
+
+impl LoxCallable for Clamp {
+    fn arity(&self) -> usize {
+        3
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let x = match &args[0] {
+            Literal::Number(x) => *x.num.borrow(),
+            arg => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'Number' for 'x' in Math.clamp(x, min, max) got '{}'",
+                    arg.get_typename()
+                )))
+            }
+        };
+        let min = match &args[1] {
+            Literal::Number(min) => *min.num.borrow(),
+            arg => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'Number' for 'min' in Math.clamp(x, min, max) got '{}'",
+                    arg.get_typename()
+                )))
+            }
+        };
+        let max = match &args[2] {
+            Literal::Number(max) => *max.num.borrow(),
+            arg => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'Number' for 'max' in Math.clamp(x, min, max) got '{}'",
+                    arg.get_typename()
+                )))
+            }
+        };
+        if min.is_nan() || max.is_nan() || min > max {
+            return Err(LoxResult::Message(
+                "Expected 'min' to be less than or equal to 'max' in Math.clamp(x, min, max)".to_string(),
+            ));
+        }
+
+        Ok(Literal::Number(Rc::new(LoxNumber::new(x.clamp(min, max)))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn clamp() {
+        assert_eq!(call(&Clamp, &[5.0, 0.0, 3.0]), 3.0);
+        assert_eq!(call(&Clamp, &[-5.0, 0.0, 3.0]), 0.0);
+        assert_eq!(call(&Clamp, &[2.0, 0.0, 3.0]), 2.0);
+        assert!(call(&Clamp, &[f64::NAN, 0.0, 3.0]).is_nan());
+        assert!(Clamp.call(None, vec![num(1.0), num(3.0), num(0.0)]).is_err());
+        assert!(Clamp.call(None, vec![num(1.0), num(f64::NAN), num(3.0)]).is_err());
+    }
+}
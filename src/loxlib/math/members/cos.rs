@@ -0,0 +1,55 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{loxnatives::LoxNative, number::loxnumber::LoxNumber},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Cos;
+
+impl Cos {
+    pub fn new() -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("cos", Rc::new(Self {}), true)))
+    }
+}
+
+impl LoxCallable for Cos {
+    fn arity(&self) -> usize {
+        1
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let x = match &args[0] {
+            Literal::Number(x) => *x.num.borrow(),
+            arg => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'Number' for 'x' in Math.cos(x) got '{}'",
+                    arg.get_typename()
+                )))
+            }
+        };
+
+        Ok(Literal::Number(Rc::new(LoxNumber::new(x.cos()))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_3, PI};
+
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn cos() {
+        for (x, expected) in [(0.0, 1.0), (FRAC_PI_2, 0.0), (PI, -1.0), (FRAC_PI_3, 0.5)] {
+            assert_close(call(&Cos, &[x]), expected);
+        }
+        assert!(call(&Cos, &[f64::NAN]).is_nan());
+        assert!(call(&Cos, &[f64::INFINITY]).is_nan());
+        assert!(Cos.call(None, vec![Literal::Bool(true)]).is_err());
+    }
+}
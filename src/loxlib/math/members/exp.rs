@@ -0,0 +1,56 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{loxnatives::LoxNative, number::loxnumber::LoxNumber},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Exp;
+
+impl Exp {
+    pub fn new() -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("exp", Rc::new(Self {}), true)))
+    }
+}
+
+impl LoxCallable for Exp {
+    fn arity(&self) -> usize {
+        1
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let x = match &args[0] {
+            Literal::Number(x) => *x.num.borrow(),
+            arg => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'Number' for 'x' in Math.exp(x) got '{}'",
+                    arg.get_typename()
+                )))
+            }
+        };
+
+        Ok(Literal::Number(Rc::new(LoxNumber::new(x.exp()))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{E, LN_2};
+
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn exp() {
+        for (x, expected) in [(0.0, 1.0), (1.0, E), (2.0, E * E), (-LN_2, 0.5)] {
+            assert_close(call(&Exp, &[x]), expected);
+        }
+        assert_eq!(call(&Exp, &[f64::NEG_INFINITY]), 0.0);
+        assert_eq!(call(&Exp, &[1000.0]), f64::INFINITY);
+        assert!(call(&Exp, &[f64::NAN]).is_nan());
+        assert!(Exp.call(None, vec![Literal::Bool(true)]).is_err());
+    }
+}
@@ -0,0 +1,44 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::loxnatives::LoxNative,
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Init;
+
+impl Init {
+    pub fn new() -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("init", Rc::new(Self {}), false)))
+    }
+}
+
+// `Math` only has static members, so it can't be instantiated.
+impl LoxCallable for Init {
+    fn arity(&self) -> usize {
+        0
+    }
+
+    fn call(&self, _: Option<&Interpreter>, _: Vec<Literal>) -> Result<Literal, LoxResult> {
+        Err(LoxResult::Message(
+            "Cannot instantiate 'Math', use its static members like Math.sqrt(x)".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::runtime::tests::{eval, run_err};
+
+    #[test]
+    fn math_cannot_be_instantiated() {
+        for src in ["Math();", "Math(1, 2);"] {
+            let err = format!("{:?}", run_err(src));
+            assert!(err.contains("Cannot instantiate 'Math'"), "{}", err);
+        }
+        assert_eq!(eval("let x = Math.sqrt(4);", "x"), "2");
+    }
+}
@@ -0,0 +1,57 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{loxnatives::LoxNative, number::loxnumber::LoxNumber},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Log;
+
+impl Log {
+    pub fn new() -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("log", Rc::new(Self {}), true)))
+    }
+}
+
+impl LoxCallable for Log {
+    fn arity(&self) -> usize {
+        1
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let x = match &args[0] {
+            Literal::Number(x) => *x.num.borrow(),
+            arg => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'Number' for 'x' in Math.log(x) got '{}'",
+                    arg.get_typename()
+                )))
+            }
+        };
+
+        Ok(Literal::Number(Rc::new(LoxNumber::new(x.ln()))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{E, LN_2};
+
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn log() {
+        for (x, expected) in [(1.0, 0.0), (E, 1.0), (E * E, 2.0), (0.5, -LN_2)] {
+            assert_close(call(&Log, &[x]), expected);
+        }
+        assert_eq!(call(&Log, &[0.0]), f64::NEG_INFINITY);
+        assert_eq!(call(&Log, &[f64::INFINITY]), f64::INFINITY);
+        assert!(call(&Log, &[-1.0]).is_nan());
+        assert!(call(&Log, &[f64::NAN]).is_nan());
+        assert!(Log.call(None, vec![Literal::Bool(true)]).is_err());
+    }
+}
@@ -0,0 +1,60 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{loxnatives::LoxNative, number::loxnumber::LoxNumber},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Min;
+
+impl Min {
+    pub fn new() -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("min", Rc::new(Self {}), false)))
+    }
+}
+
+impl LoxCallable for Min {
+    fn arity(&self) -> usize {
+        1
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        if args.is_empty() {
+            return Err(LoxResult::Message(
+                "Expected at least 1 arguments but got 0 for 'min'".to_string(),
+            ));
+        }
+
+        let mut result = f64::INFINITY;
+        for arg in args.iter() {
+            match arg {
+                Literal::Number(n) => result = result.min(*n.num.borrow()),
+                _ => {
+                    return Err(LoxResult::Message(format!(
+                        "Expected type 'Number' for every argument in Math.min(...) got '{}'",
+                        arg.get_typename()
+                    )))
+                }
+            }
+        }
+        Ok(Literal::Number(Rc::new(LoxNumber::new(result))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn min() {
+        assert_eq!(call(&Min, &[3.0, -1.0, 7.0]), -1.0);
+        assert_eq!(call(&Min, &[2.0]), 2.0);
+        assert_eq!(call(&Min, &[f64::NAN, 1.0]), 1.0);
+        assert!(Min.call(None, Vec::new()).is_err());
+        assert!(Min.call(None, vec![num(1.0), Literal::Bool(true)]).is_err());
+    }
+}
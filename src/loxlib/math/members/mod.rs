@@ -0,0 +1,34 @@
+pub mod sqrt;
+pub mod pow;
+pub mod sin;
+pub mod cos;
+pub mod tan;
+pub mod log;
+pub mod exp;
+pub mod min;
+pub mod max;
+pub mod clamp;
+pub mod init;
+
+#[cfg(test)]
+pub mod tests {
+    use std::rc::Rc;
+
+    use crate::{
+        lexer::literal::Literal, loxlib::number::loxnumber::LoxNumber, runtime::callable::LoxCallable,
+    };
+
+    pub fn num(n: f64) -> Literal {
+        Literal::Number(Rc::new(LoxNumber::new(n)))
+    }
+
+    // Calls a numeric member and unwraps the number it returns.
+    pub fn call(member: &dyn LoxCallable, args: &[f64]) -> f64 {
+        let args = args.iter().map(|n| num(*n)).collect();
+        member.call(None, args).unwrap().unwrap_number()
+    }
+
+    pub fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-12, "expected {} got {}", expected, actual);
+    }
+}
@@ -0,0 +1,63 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{loxnatives::LoxNative, number::loxnumber::LoxNumber},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Pow;
+
+impl Pow {
+    pub fn new() -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("pow", Rc::new(Self {}), true)))
+    }
+}
+
+impl LoxCallable for Pow {
+    fn arity(&self) -> usize {
+        2
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let base = match &args[0] {
+            Literal::Number(base) => *base.num.borrow(),
+            arg => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'Number' for 'base' in Math.pow(base, exponent) got '{}'",
+                    arg.get_typename()
+                )))
+            }
+        };
+        let exponent = match &args[1] {
+            Literal::Number(exponent) => *exponent.num.borrow(),
+            arg => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'Number' for 'exponent' in Math.pow(base, exponent) got '{}'",
+                    arg.get_typename()
+                )))
+            }
+        };
+
+        Ok(Literal::Number(Rc::new(LoxNumber::new(base.powf(exponent)))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn pow() {
+        assert_eq!(call(&Pow, &[2.0, 10.0]), 1024.0);
+        assert_eq!(call(&Pow, &[4.0, 0.5]), 2.0);
+        assert_eq!(call(&Pow, &[2.0, -1.0]), 0.5);
+        assert_eq!(call(&Pow, &[f64::NAN, 0.0]), 1.0);
+        assert!(call(&Pow, &[-8.0, 1.0 / 3.0]).is_nan());
+        assert!(call(&Pow, &[2.0, f64::NAN]).is_nan());
+        assert!(Pow.call(None, vec![num(2.0), Literal::Bool(true)]).is_err());
+    }
+}
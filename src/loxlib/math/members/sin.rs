@@ -0,0 +1,55 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{loxnatives::LoxNative, number::loxnumber::LoxNumber},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Sin;
+
+impl Sin {
+    pub fn new() -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("sin", Rc::new(Self {}), true)))
+    }
+}
+
+impl LoxCallable for Sin {
+    fn arity(&self) -> usize {
+        1
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let x = match &args[0] {
+            Literal::Number(x) => *x.num.borrow(),
+            arg => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'Number' for 'x' in Math.sin(x) got '{}'",
+                    arg.get_typename()
+                )))
+            }
+        };
+
+        Ok(Literal::Number(Rc::new(LoxNumber::new(x.sin()))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_6, PI};
+
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn sin() {
+        for (x, expected) in [(0.0, 0.0), (FRAC_PI_2, 1.0), (-FRAC_PI_2, -1.0), (FRAC_PI_6, 0.5), (PI, 0.0)] {
+            assert_close(call(&Sin, &[x]), expected);
+        }
+        assert!(call(&Sin, &[f64::NAN]).is_nan());
+        assert!(call(&Sin, &[f64::INFINITY]).is_nan());
+        assert!(Sin.call(None, vec![Literal::Bool(true)]).is_err());
+    }
+}
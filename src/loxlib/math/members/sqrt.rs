@@ -0,0 +1,55 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{loxnatives::LoxNative, number::loxnumber::LoxNumber},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Sqrt;
+
+impl Sqrt {
+    pub fn new() -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("sqrt", Rc::new(Self {}), true)))
+    }
+}
+
+impl LoxCallable for Sqrt {
+    fn arity(&self) -> usize {
+        1
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let x = match &args[0] {
+            Literal::Number(x) => *x.num.borrow(),
+            arg => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'Number' for 'x' in Math.sqrt(x) got '{}'",
+                    arg.get_typename()
+                )))
+            }
+        };
+
+        Ok(Literal::Number(Rc::new(LoxNumber::new(x.sqrt()))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::SQRT_2;
+
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn sqrt() {
+        for (x, expected) in [(16.0, 4.0), (2.0, SQRT_2), (0.0, 0.0), (f64::INFINITY, f64::INFINITY)] {
+            assert_eq!(call(&Sqrt, &[x]), expected);
+        }
+        assert!(call(&Sqrt, &[-1.0]).is_nan());
+        assert!(call(&Sqrt, &[f64::NAN]).is_nan());
+        assert!(Sqrt.call(None, vec![Literal::Bool(true)]).is_err());
+    }
+}
@@ -0,0 +1,56 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{loxnatives::LoxNative, number::loxnumber::LoxNumber},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Tan;
+
+impl Tan {
+    pub fn new() -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("tan", Rc::new(Self {}), true)))
+    }
+}
+
+impl LoxCallable for Tan {
+    fn arity(&self) -> usize {
+        1
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let x = match &args[0] {
+            Literal::Number(x) => *x.num.borrow(),
+            arg => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'Number' for 'x' in Math.tan(x) got '{}'",
+                    arg.get_typename()
+                )))
+            }
+        };
+
+        Ok(Literal::Number(Rc::new(LoxNumber::new(x.tan()))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn tan() {
+        for (x, expected) in [(0.0, 0.0), (FRAC_PI_4, 1.0), (-FRAC_PI_4, -1.0), (PI, 0.0)] {
+            assert_close(call(&Tan, &[x]), expected);
+        }
+        assert!(call(&Tan, &[FRAC_PI_2]).abs() > 1e15);
+        assert!(call(&Tan, &[f64::NAN]).is_nan());
+        assert!(call(&Tan, &[f64::INFINITY]).is_nan());
+        assert!(Tan.call(None, vec![Literal::Bool(true)]).is_err());
+    }
+}
@@ -0,0 +1,2 @@
+pub mod math_class_members;
+pub mod members;
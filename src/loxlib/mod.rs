@@ -2,8 +2,15 @@ pub mod loxnatives;
 pub mod number;
 pub mod array;
 pub mod clock;
+pub mod format;
+pub mod hash;
 pub mod native_method;
 pub mod print;
+pub mod random;
 pub mod input;
+pub mod math;
 pub mod string;
 pub mod typeofliteral;
+
+// Largest array or string a native builds from a count, like `Array.range(n)`.
+pub const MAX_LENGTH: usize = 1 << 24;
@@ -17,8 +17,8 @@ struct NumberClass {
 }
 
 impl NumberClass {
-    fn new() -> Self {
-        let members = NumberMembers::new();
+    fn new(num: &NumberData) -> Self {
+        let members = NumberMembers::new(num.clone());
         Self {
             klass: LoxClass::new(
                 "Number",
@@ -41,7 +41,7 @@ pub struct LoxNumber {
 impl LoxNumber {
     pub fn new(num: f64) -> Self {
         let num = Rc::new(RefCell::new(num));
-        let cl = NumberClass::new();
+        let cl = NumberClass::new(&num);
         let inst = LoxInstance::new(&cl.klass, cl.fields.clone());
         Self {
             num,
@@ -51,6 +51,6 @@ impl LoxNumber {
     }
 
     pub fn get(&self, name: &Token) -> Result<Literal, LoxResult> {
-        return self.num_inst.get(name, &self.num_inst)
+        self.num_inst.get(name, &self.num_inst, false)
     }
 }
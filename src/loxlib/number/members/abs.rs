@@ -0,0 +1,45 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{loxnatives::LoxNative, number::{loxnumber::LoxNumber, NumberData}},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Abs {
+    num: NumberData,
+}
+
+impl Abs {
+    pub fn new(num: NumberData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("abs", Rc::new(Self { num }), true)))
+    }
+}
+
+impl LoxCallable for Abs {
+    fn arity(&self) -> usize {
+        0
+    }
+
+    fn call(&self, _: Option<&Interpreter>, _: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let num = *self.num.borrow();
+        Ok(Literal::Number(Rc::new(LoxNumber::new(num.abs()))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn abs() {
+        for (num, expected) in [(-2.5, 2.5), (3.0, 3.0)] {
+            let abs = Abs { num: Rc::new(RefCell::new(num)) };
+            assert_eq!(abs.call(None, Vec::new()).unwrap().unwrap_number(), expected);
+        }
+    }
+}
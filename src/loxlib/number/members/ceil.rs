@@ -0,0 +1,45 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{loxnatives::LoxNative, number::{loxnumber::LoxNumber, NumberData}},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Ceil {
+    num: NumberData,
+}
+
+impl Ceil {
+    pub fn new(num: NumberData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("ceil", Rc::new(Self { num }), true)))
+    }
+}
+
+impl LoxCallable for Ceil {
+    fn arity(&self) -> usize {
+        0
+    }
+
+    fn call(&self, _: Option<&Interpreter>, _: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let num = *self.num.borrow();
+        Ok(Literal::Number(Rc::new(LoxNumber::new(num.ceil()))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn ceil() {
+        for (num, expected) in [(-2.5, -2.0), (2.1, 3.0)] {
+            let ceil = Ceil { num: Rc::new(RefCell::new(num)) };
+            assert_eq!(ceil.call(None, Vec::new()).unwrap().unwrap_number(), expected);
+        }
+    }
+}
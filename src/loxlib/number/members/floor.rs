@@ -0,0 +1,45 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{loxnatives::LoxNative, number::{loxnumber::LoxNumber, NumberData}},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Floor {
+    num: NumberData,
+}
+
+impl Floor {
+    pub fn new(num: NumberData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("floor", Rc::new(Self { num }), true)))
+    }
+}
+
+impl LoxCallable for Floor {
+    fn arity(&self) -> usize {
+        0
+    }
+
+    fn call(&self, _: Option<&Interpreter>, _: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let num = *self.num.borrow();
+        Ok(Literal::Number(Rc::new(LoxNumber::new(num.floor()))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn floor() {
+        for (num, expected) in [(-2.5, -3.0), (2.7, 2.0)] {
+            let floor = Floor { num: Rc::new(RefCell::new(num)) };
+            assert_eq!(floor.call(None, Vec::new()).unwrap().unwrap_number(), expected);
+        }
+    }
+}
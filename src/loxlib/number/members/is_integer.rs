@@ -0,0 +1,45 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{loxnatives::LoxNative, number::NumberData},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct IsInteger {
+    num: NumberData,
+}
+
+impl IsInteger {
+    pub fn new(num: NumberData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("is_integer", Rc::new(Self { num }), true)))
+    }
+}
+
+impl LoxCallable for IsInteger {
+    fn arity(&self) -> usize {
+        0
+    }
+
+    fn call(&self, _: Option<&Interpreter>, _: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let num = *self.num.borrow();
+        Ok(Literal::Bool(num.is_finite() && num.fract() == 0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn is_integer() {
+        for (num, expected) in [(3.0, true), (-0.0, true), (2.5, false), (f64::INFINITY, false)] {
+            let is_integer = IsInteger { num: Rc::new(RefCell::new(num)) };
+            assert_eq!(is_integer.call(None, Vec::new()).unwrap(), Literal::Bool(expected));
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{loxnatives::LoxNative, number::NumberData},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct IsNan {
+    num: NumberData,
+}
+
+impl IsNan {
+    pub fn new(num: NumberData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("is_nan", Rc::new(Self { num }), true)))
+    }
+}
+
+impl LoxCallable for IsNan {
+    fn arity(&self) -> usize {
+        0
+    }
+
+    fn call(&self, _: Option<&Interpreter>, _: Vec<Literal>) -> Result<Literal, LoxResult> {
+        Ok(Literal::Bool(self.num.borrow().is_nan()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn is_nan() {
+        let nan = IsNan { num: Rc::new(RefCell::new(f64::NAN)) };
+        let one = IsNan { num: Rc::new(RefCell::new(1.0)) };
+        assert_eq!(nan.call(None, Vec::new()).unwrap(), Literal::Bool(true));
+        assert_eq!(one.call(None, Vec::new()).unwrap(), Literal::Bool(false));
+    }
+}
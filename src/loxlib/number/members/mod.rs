@@ -1,2 +1,10 @@
 pub mod init;
 pub mod tryparse;
+pub mod abs;
+pub mod floor;
+pub mod ceil;
+pub mod round;
+pub mod to_fixed;
+pub mod to_string;
+pub mod is_nan;
+pub mod is_integer;
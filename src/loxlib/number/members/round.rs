@@ -0,0 +1,46 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{loxnatives::LoxNative, number::{loxnumber::LoxNumber, NumberData}},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Round {
+    num: NumberData,
+}
+
+impl Round {
+    pub fn new(num: NumberData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("round", Rc::new(Self { num }), true)))
+    }
+}
+
+impl LoxCallable for Round {
+    fn arity(&self) -> usize {
+        0
+    }
+
+    fn call(&self, _: Option<&Interpreter>, _: Vec<Literal>) -> Result<Literal, LoxResult> {
+        // Halves round away from zero, 2.5 becomes 3 and -2.5 becomes -3
+        let num = *self.num.borrow();
+        Ok(Literal::Number(Rc::new(LoxNumber::new(num.round()))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn round() {
+        for (num, rounded) in [(2.5, 3.0), (-2.5, -3.0), (2.4, 2.0)] {
+            let round = Round { num: Rc::new(RefCell::new(num)) };
+            assert_eq!(round.call(None, Vec::new()).unwrap().unwrap_number(), rounded);
+        }
+    }
+}
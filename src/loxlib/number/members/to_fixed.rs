@@ -0,0 +1,66 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{
+        loxnatives::LoxNative,
+        number::NumberData,
+        string::loxstring::LoxString,
+    },
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct ToFixed {
+    num: NumberData,
+}
+
+impl ToFixed {
+    pub fn new(num: NumberData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("to_fixed", Rc::new(Self { num }), true)))
+    }
+}
+
+impl LoxCallable for ToFixed {
+    fn arity(&self) -> usize {
+        1
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let digits = args.first().unwrap();
+        if digits.get_typename() != "Number" {
+            return Err(LoxResult::Message(format!(
+                "Expected type 'Number' for 'digits' in Number.to_fixed(digits) got '{}'",
+                digits.get_typename()
+            )));
+        }
+        let digits = digits.unwrap_number();
+        if !(0.0..=100.0).contains(&digits) {
+            return Err(LoxResult::Message(
+                "Expected 'digits' to be between 0 and 100 in Number.to_fixed(digits)".to_string(),
+            ));
+        }
+
+        let string = format!("{:.*}", digits as usize, *self.num.borrow());
+        Ok(Literal::Str(Rc::new(LoxString::new(string))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+    use crate::loxlib::number::loxnumber::LoxNumber;
+
+    #[test]
+    fn to_fixed() {
+        let to_fixed = ToFixed { num: Rc::new(RefCell::new(3.14159)) };
+        let two = vec![Literal::Number(Rc::new(LoxNumber::new(2.0)))];
+        let zero = vec![Literal::Number(Rc::new(LoxNumber::new(0.0)))];
+        assert_eq!(to_fixed.call(None, two).unwrap().unwrap_str(), "3.14");
+        assert_eq!(to_fixed.call(None, zero).unwrap().unwrap_str(), "3");
+        assert!(to_fixed.call(None, vec![Literal::Bool(true)]).is_err());
+    }
+}
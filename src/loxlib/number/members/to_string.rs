@@ -0,0 +1,106 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{
+        loxnatives::LoxNative,
+        number::NumberData,
+        string::loxstring::LoxString,
+    },
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct ToString {
+    num: NumberData,
+}
+
+impl ToString {
+    pub fn new(num: NumberData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("to_string", Rc::new(Self { num }), false)))
+    }
+}
+
+impl LoxCallable for ToString {
+    fn arity(&self) -> usize {
+        1
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        if args.len() > 1 {
+            return Err(LoxResult::Message(format!(
+                "Expected 0 to 1 arguments but got {} for 'to_string'",
+                args.len()
+            )));
+        }
+        let radix = match args.first() {
+            Some(Literal::Number(radix)) => *radix.num.borrow(),
+            Some(radix) => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'Number' for 'radix' in Number.to_string(radix) got '{}'",
+                    radix.get_typename()
+                )))
+            }
+            None => 10.0,
+        };
+        if radix.fract() != 0.0 || !(2.0..=36.0).contains(&radix) {
+            return Err(LoxResult::Message(
+                "Expected 'radix' to be an integer between 2 and 36 in Number.to_string(radix)".to_string(),
+            ));
+        }
+
+        let num = *self.num.borrow();
+        if radix == 10.0 {
+            return Ok(Literal::Str(Rc::new(LoxString::new(num.to_string()))));
+        }
+        if num.fract() != 0.0 || !num.is_finite() {
+            return Err(LoxResult::Message(
+                "Only integers can be converted to a radix other than 10 in Number.to_string(radix)".to_string(),
+            ));
+        }
+
+        let mut digits = Vec::new();
+        let mut n = num.abs() as u64;
+        loop {
+            digits.push(std::char::from_digit((n % radix as u64) as u32, radix as u32).unwrap());
+            n /= radix as u64;
+            if n == 0 {
+                break;
+            }
+        }
+        if num < 0.0 {
+            digits.push('-');
+        }
+        let string = digits.into_iter().rev().collect();
+        Ok(Literal::Str(Rc::new(LoxString::new(string))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+    use crate::loxlib::number::loxnumber::LoxNumber;
+
+    fn radix(n: f64) -> Vec<Literal> {
+        vec![Literal::Number(Rc::new(LoxNumber::new(n)))]
+    }
+
+    #[test]
+    fn to_string() {
+        let to_string = ToString { num: Rc::new(RefCell::new(-255.0)) };
+        assert_eq!(to_string.call(None, Vec::new()).unwrap().unwrap_str(), "-255");
+        assert_eq!(to_string.call(None, radix(16.0)).unwrap().unwrap_str(), "-ff");
+        assert_eq!(to_string.call(None, radix(2.0)).unwrap().unwrap_str(), "-11111111");
+        assert!(to_string.call(None, radix(1.0)).is_err());
+    }
+
+    #[test]
+    fn to_string_fraction() {
+        let to_string = ToString { num: Rc::new(RefCell::new(2.5)) };
+        assert_eq!(to_string.call(None, Vec::new()).unwrap().unwrap_str(), "2.5");
+        assert!(to_string.call(None, radix(2.0)).is_err());
+    }
+}
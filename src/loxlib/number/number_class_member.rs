@@ -1,18 +1,30 @@
 use std::collections::HashMap;
 
 use crate::{lexer::literal::Literal, runtime::loxinstance::InstanceField};
-use super::members::*;
+use super::{members::*, NumberData};
 
-pub struct NumberMembers;
+pub struct NumberMembers {
+    num: NumberData
+}
 
 impl NumberMembers {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(num: NumberData) -> Self {
+        Self {
+            num
+        }
     }
 
     pub fn get_methods(&self) -> HashMap<String, Literal> {
         let mut map: HashMap<String, Literal> = HashMap::new();        
         map.insert(String::from("init"), init::Init::new());
+        map.insert(String::from("abs"), abs::Abs::new(self.num.clone()));
+        map.insert(String::from("floor"), floor::Floor::new(self.num.clone()));
+        map.insert(String::from("ceil"), ceil::Ceil::new(self.num.clone()));
+        map.insert(String::from("round"), round::Round::new(self.num.clone()));
+        map.insert(String::from("to_fixed"), to_fixed::ToFixed::new(self.num.clone()));
+        map.insert(String::from("to_string"), to_string::ToString::new(self.num.clone()));
+        map.insert(String::from("is_nan"), is_nan::IsNan::new(self.num.clone()));
+        map.insert(String::from("is_integer"), is_integer::IsInteger::new(self.num.clone()));
         map
     }
 
@@ -10,9 +10,13 @@ impl LoxCallable for Print {
         0
     }
 
-    fn call(&self, _interpreter: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+    fn call(&self, interpreter: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
         for arg in args.iter() {
-            print!("{}", arg.get_value());
+            let str = match interpreter {
+                Some(interpreter) => interpreter.stringify(arg)?,
+                None => arg.get_value(),
+            };
+            print!("{}", str);
             print!(" ");
         }
         println!("");
@@ -0,0 +1,71 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{loxnatives::LoxNative, random::RandomData},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Choice {
+    rng: RandomData,
+}
+
+impl Choice {
+    pub fn new(rng: RandomData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new(
+            "choice",
+            Rc::new(Self { rng }),
+            true,
+        )))
+    }
+}
+
+impl LoxCallable for Choice {
+    fn arity(&self) -> usize {
+        1
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let arr = match args.first().unwrap() {
+            Literal::Array(arr) => arr.clone(),
+            arg => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'Array' for 'arr' in Random.choice(arr) got '{}'",
+                    arg.get_typename()
+                )))
+            }
+        };
+
+        let arr = arr.array.borrow();
+        if arr.is_empty() {
+            return Err(LoxResult::Message(
+                "Cannot choose from an empty array in Random.choice(arr)".to_string(),
+            ));
+        }
+        let index = self.rng.borrow_mut().below(arr.len() as u64) as usize;
+        Ok(arr[index].dup())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+    use crate::loxlib::array::loxarray::LoxArray;
+
+    #[test]
+    fn choice() {
+        let choice = Choice { rng: rng() };
+        let elems = vec![
+            num(1.0),
+            Literal::Bool(true),
+        ];
+        let arr = Literal::Array(Rc::new(LoxArray::new(elems.clone())));
+        let chosen = choice.call(None, vec![arr]).unwrap();
+        assert!(elems.contains(&chosen));
+        let empty = Literal::Array(Rc::new(LoxArray::new(Vec::new())));
+        assert!(choice.call(None, vec![empty]).is_err());
+    }
+}
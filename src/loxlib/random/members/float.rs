@@ -0,0 +1,49 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{loxnatives::LoxNative, number::loxnumber::LoxNumber, random::RandomData},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Float {
+    rng: RandomData,
+}
+
+impl Float {
+    pub fn new(rng: RandomData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new(
+            "float",
+            Rc::new(Self { rng }),
+            true,
+        )))
+    }
+}
+
+impl LoxCallable for Float {
+    fn arity(&self) -> usize {
+        0
+    }
+
+    fn call(&self, _: Option<&Interpreter>, _: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let float = self.rng.borrow_mut().next_f64();
+        Ok(Literal::Number(Rc::new(LoxNumber::new(float))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn float() {
+        let float = Float { rng: rng() };
+        for _ in 0..100 {
+            let f = float.call(None, Vec::new()).unwrap().unwrap_number();
+            assert!((0.0..1.0).contains(&f));
+        }
+    }
+}
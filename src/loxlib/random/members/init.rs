@@ -0,0 +1,95 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{
+        loxnatives::LoxNative,
+        random::{prng::Prng, random_class_members::RandomMembers},
+    },
+    runtime::interpreter::Interpreter,
+    runtime::{callable::LoxCallable, loxclass::LoxClass, loxinstance::LoxInstance},
+};
+
+pub struct Init;
+
+impl Init {
+    pub fn new() -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("init", Rc::new(Self {}), false)))
+    }
+}
+
+impl LoxCallable for Init {
+    fn arity(&self) -> usize {
+        1
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let prng = match args.as_slice() {
+            [] => Prng::from_time(),
+            [Literal::Number(seed)] if seed.num.borrow().is_nan() => {
+                return Err(LoxResult::Message(
+                    "Expected 'seed' to be a number in Random(seed) got NaN".to_string(),
+                ))
+            }
+            [Literal::Number(seed)] => Prng::new(seed.num.borrow().to_bits()),
+            [seed] => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'Number' for 'seed' in Random(seed) got '{}'",
+                    seed.get_typename()
+                )))
+            }
+            _ => {
+                return Err(LoxResult::Message(format!(
+                    "Expected 0 to 1 arguments but got {} for 'Random'",
+                    args.len()
+                )))
+            }
+        };
+
+        // Every generator gets its own methods bound to its own state
+        let members = RandomMembers::new(Rc::new(RefCell::new(prng)));
+        let klass = LoxClass::new(
+            "Random",
+            members.get_methods(),
+            members.get_statics(),
+            members.get_fields(),
+        );
+        let fields = Rc::new(RefCell::new(members.get_fields()));
+        Ok(Literal::Instance(Rc::new(LoxInstance::new(&klass, fields))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+    use crate::lexer::token::Token;
+
+    fn next(random: &Literal) -> f64 {
+        let instance = match random {
+            Literal::Instance(instance) => instance,
+            _ => panic!("Random(seed) did not return an instance"),
+        };
+        let name = Token::new(
+            crate::lexer::tokentype::TokenType::Identifier,
+            "next".to_string(),
+            None,
+            0,
+        );
+        match instance.get(&name, instance, false).unwrap() {
+            Literal::Native(next) => next.native.call(None, Vec::new()).unwrap().unwrap_number(),
+            _ => panic!("Random.next is not a native method"),
+        }
+    }
+
+    #[test]
+    fn seeded() {
+        let seed = || vec![num(7.0)];
+        let a = Init.call(None, seed()).unwrap();
+        let b = Init.call(None, seed()).unwrap();
+        assert_eq!(next(&a), next(&b));
+        assert!(Init.call(None, vec![Literal::Bool(true)]).is_err());
+        assert!(Init.call(None, vec![num(f64::NAN)]).is_err());
+    }
+}
@@ -0,0 +1,134 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{loxnatives::LoxNative, number::loxnumber::LoxNumber, random::RandomData},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+// Widest range `int` can draw from, every value in it is an exact Number.
+const MAX_SPAN: f64 = (1u64 << 53) as f64;
+
+pub struct Int {
+    rng: RandomData,
+}
+
+impl Int {
+    pub fn new(rng: RandomData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("int", Rc::new(Self { rng }), true)))
+    }
+}
+
+impl LoxCallable for Int {
+    fn arity(&self) -> usize {
+        2
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let mut bounds = [0.0; 2];
+        for (i, name) in ["lo", "hi"].iter().enumerate() {
+            match &args[i] {
+                Literal::Number(n) if n.num.borrow().fract() == 0.0 => bounds[i] = *n.num.borrow(),
+                Literal::Number(_) => {
+                    return Err(LoxResult::Message(format!(
+                        "Expected an integer for '{}' in Random.int(lo, hi) got {}",
+                        name,
+                        args[i].repr()
+                    )))
+                }
+                arg => {
+                    return Err(LoxResult::Message(format!(
+                        "Expected type 'Number' for '{}' in Random.int(lo, hi) got '{}'",
+                        name,
+                        arg.get_typename()
+                    )))
+                }
+            }
+        }
+        let [lo, hi] = bounds;
+        if lo > hi {
+            return Err(LoxResult::Message(
+                "Expected 'lo' to be less than or equal to 'hi' in Random.int(lo, hi)".to_string(),
+            ));
+        }
+
+        // Both ends are included
+        let span = Some(hi - lo)
+            .filter(|span| *span < MAX_SPAN)
+            .and_then(|span| (span as u64).checked_add(1))
+            .ok_or_else(|| {
+                LoxResult::Message(
+                    "Expected 'hi' - 'lo' to be less than 2^53 in Random.int(lo, hi)".to_string(),
+                )
+            })?;
+        let offset = self.rng.borrow_mut().below(span);
+        Ok(Literal::Number(Rc::new(LoxNumber::new(lo + offset as f64))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn int() {
+        let int = Int { rng: rng() };
+        let mut seen = [false; 3];
+        for _ in 0..100 {
+            let n = int
+                .call(None, vec![num(-1.0), num(1.0)])
+                .unwrap()
+                .unwrap_number();
+            seen[(n + 1.0) as usize] = true;
+        }
+        assert_eq!(seen, [true; 3]);
+        assert_eq!(
+            int.call(None, vec![num(5.0), num(5.0)])
+                .unwrap()
+                .unwrap_number(),
+            5.0
+        );
+    }
+
+    #[test]
+    fn int_invalid() {
+        let int = Int { rng: rng() };
+        assert!(int.call(None, vec![num(2.0), num(1.0)]).is_err());
+        assert!(int.call(None, vec![num(0.5), num(1.0)]).is_err());
+        assert!(int.call(None, vec![num(0.0), num(f64::NAN)]).is_err());
+        assert!(int.call(None, vec![num(0.0), num(f64::INFINITY)]).is_err());
+    }
+
+    fn message(result: Result<Literal, LoxResult>) -> String {
+        match result {
+            Err(LoxResult::Message(message)) => message,
+            _ => panic!("expected an error message"),
+        }
+    }
+
+    #[test]
+    fn int_messages() {
+        let int = Int { rng: rng() };
+        let msg = message(int.call(None, vec![num(1.5), num(2.0)]));
+        assert_eq!(msg, "Expected an integer for 'lo' in Random.int(lo, hi) got 1.5");
+        let msg = message(int.call(None, vec![num(0.0), Literal::Bool(true)]));
+        assert_eq!(msg, "Expected type 'Number' for 'hi' in Random.int(lo, hi) got 'Bool'");
+    }
+
+    #[test]
+    fn int_range_limit() {
+        let int = Int { rng: rng() };
+        let max = (1u64 << 53) as f64;
+        for (lo, hi) in [(0.0, 1e20), (0.0, max), (-max, max), (-1e300, 1e300)] {
+            let msg = message(int.call(None, vec![num(lo), num(hi)]));
+            assert!(msg.contains("less than 2^53"), "{}", msg);
+        }
+        let n = int.call(None, vec![num(0.0), num(max - 1.0)]).unwrap().unwrap_number();
+        assert!((0.0..max).contains(&n));
+        let n = int.call(None, vec![num(1e20), num(1e20)]).unwrap().unwrap_number();
+        assert_eq!(n, 1e20);
+    }
+}
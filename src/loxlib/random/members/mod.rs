@@ -0,0 +1,26 @@
+pub mod choice;
+pub mod float;
+pub mod init;
+pub mod int;
+pub mod next;
+pub mod sample;
+pub mod shuffle;
+
+#[cfg(test)]
+pub mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use crate::{
+        lexer::literal::Literal,
+        loxlib::{number::loxnumber::LoxNumber, random::{prng::Prng, RandomData}},
+    };
+
+    // Fixed seed so failures can be reproduced.
+    pub fn rng() -> RandomData {
+        Rc::new(RefCell::new(Prng::new(42)))
+    }
+
+    pub fn num(n: f64) -> Literal {
+        Literal::Number(Rc::new(LoxNumber::new(n)))
+    }
+}
@@ -0,0 +1,47 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{loxnatives::LoxNative, number::loxnumber::LoxNumber, random::RandomData},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Next {
+    rng: RandomData,
+}
+
+impl Next {
+    pub fn new(rng: RandomData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("next", Rc::new(Self { rng }), true)))
+    }
+}
+
+impl LoxCallable for Next {
+    fn arity(&self) -> usize {
+        0
+    }
+
+    fn call(&self, _: Option<&Interpreter>, _: Vec<Literal>) -> Result<Literal, LoxResult> {
+        // Keeps 53 bits so the integer is exactly representable as a Number
+        let next = self.rng.borrow_mut().next_u64() >> 11;
+        Ok(Literal::Number(Rc::new(LoxNumber::new(next as f64))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn next() {
+        let (a, b) = (Next { rng: rng() }, Next { rng: rng() });
+        for _ in 0..10 {
+            let n = a.call(None, Vec::new()).unwrap().unwrap_number();
+            assert_eq!(n, b.call(None, Vec::new()).unwrap().unwrap_number());
+            assert!(n.fract() == 0.0 && n >= 0.0);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{array::loxarray::LoxArray, loxnatives::LoxNative, random::RandomData},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Sample {
+    rng: RandomData,
+}
+
+impl Sample {
+    pub fn new(rng: RandomData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new(
+            "sample",
+            Rc::new(Self { rng }),
+            true,
+        )))
+    }
+}
+
+impl LoxCallable for Sample {
+    fn arity(&self) -> usize {
+        2
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let arr = match args.first().unwrap() {
+            Literal::Array(arr) => arr.clone(),
+            arg => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'Array' for 'arr' in Random.sample(arr, k) got '{}'",
+                    arg.get_typename()
+                )))
+            }
+        };
+        let k = match &args[1] {
+            Literal::Number(k) => *k.num.borrow(),
+            arg => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'Number' for 'k' in Random.sample(arr, k) got '{}'",
+                    arg.get_typename()
+                )))
+            }
+        };
+        if k.is_nan() || k < 0.0 || k.fract() != 0.0 {
+            return Err(LoxResult::Message(format!(
+                "Expected 'k' to be a non negative integer in Random.sample(arr, k) got {}",
+                args[1].repr()
+            )));
+        }
+        let k = k as usize;
+
+        let mut elems = arr.array.borrow().clone();
+        if k > elems.len() {
+            return Err(LoxResult::Message(format!(
+                "Cannot sample {} elements from an array of {} in Random.sample(arr, k)",
+                k,
+                elems.len()
+            )));
+        }
+        // Partial Fisher-Yates, the first k positions end up as the sample
+        for i in 0..k {
+            let j = i + self.rng.borrow_mut().below((elems.len() - i) as u64) as usize;
+            elems.swap(i, j);
+        }
+        elems.truncate(k);
+        Ok(Literal::Array(Rc::new(LoxArray::new(elems))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn sample() {
+        let sample = Sample { rng: rng() };
+        let arr = Literal::Array(Rc::new(LoxArray::new(
+            (0..10).map(|n| num(n as f64)).collect(),
+        )));
+        let result = sample.call(None, vec![arr.dup(), num(4.0)]).unwrap();
+        let mut picked: Vec<f64> = match result {
+            Literal::Array(picked) => picked
+                .array
+                .borrow()
+                .iter()
+                .map(|n| n.unwrap_number())
+                .collect(),
+            _ => panic!("Random.sample did not return an array"),
+        };
+        assert_eq!(picked.len(), 4);
+        picked.sort_by(f64::total_cmp);
+        picked.dedup();
+        assert_eq!(picked.len(), 4);
+        assert!(sample.call(None, vec![arr.dup(), num(11.0)]).is_err());
+        assert!(sample.call(None, vec![arr.dup(), Literal::Bool(true)]).is_err());
+        for k in [-1.0, 1.5, f64::NAN, f64::INFINITY] {
+            match sample.call(None, vec![arr.dup(), num(k)]) {
+                Err(LoxResult::Message(msg)) => {
+                    assert!(msg.contains("non negative integer"), "{}", msg)
+                }
+                _ => panic!("Random.sample accepted k = {}", k),
+            }
+        }
+        let empty = sample.call(None, vec![arr, num(0.0)]).unwrap();
+        assert!(matches!(empty, Literal::Array(empty) if empty.array.borrow().is_empty()));
+    }
+}
@@ -0,0 +1,78 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{loxnatives::LoxNative, random::RandomData},
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Shuffle {
+    rng: RandomData,
+}
+
+impl Shuffle {
+    pub fn new(rng: RandomData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new(
+            "shuffle",
+            Rc::new(Self { rng }),
+            true,
+        )))
+    }
+}
+
+impl LoxCallable for Shuffle {
+    fn arity(&self) -> usize {
+        1
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let arr = match args.first().unwrap() {
+            Literal::Array(arr) => arr.clone(),
+            arg => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'Array' for 'arr' in Random.shuffle(arr) got '{}'",
+                    arg.get_typename()
+                )))
+            }
+        };
+
+        // Fisher-Yates, every permutation is equally likely
+        let mut arr = arr.array.borrow_mut();
+        for i in (1..arr.len()).rev() {
+            let j = self.rng.borrow_mut().below(i as u64 + 1) as usize;
+            arr.swap(i, j);
+        }
+        Ok(Literal::None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+    use crate::loxlib::array::loxarray::LoxArray;
+
+    #[test]
+    fn shuffle() {
+        let shuffle = Shuffle { rng: rng() };
+        let elems: Vec<Literal> = (0..20)
+            .map(|n| num(n as f64))
+            .collect();
+        let arr = Rc::new(LoxArray::new(elems.clone()));
+        shuffle
+            .call(None, vec![Literal::Array(arr.clone())])
+            .unwrap();
+
+        let mut shuffled: Vec<f64> = arr
+            .array
+            .borrow()
+            .iter()
+            .map(|n| n.unwrap_number())
+            .collect();
+        assert_ne!(*arr.array.borrow(), elems);
+        shuffled.sort_by(f64::total_cmp);
+        assert_eq!(shuffled, (0..20).map(|n| n as f64).collect::<Vec<f64>>());
+    }
+}
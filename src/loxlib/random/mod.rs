@@ -0,0 +1,6 @@
+use std::{cell::RefCell, rc::Rc};
+
+pub mod members;
+pub mod prng;
+pub mod random_class_members;
+type RandomData = Rc<RefCell<prng::Prng>>;
@@ -0,0 +1,49 @@
+use std::collections::HashMap;
+
+use super::{members::*, RandomData};
+use crate::{lexer::literal::Literal, runtime::loxinstance::InstanceField};
+
+pub struct RandomMembers {
+    rng: RandomData,
+}
+
+impl RandomMembers {
+    pub fn new(rng: RandomData) -> Self {
+        Self { rng }
+    }
+
+    pub fn get_methods(&self) -> HashMap<String, Literal> {
+        let mut map = self.generator();
+        map.insert(String::from("init"), init::Init::new());
+        map
+    }
+
+    // The class itself works as the default generator, `Random.int(1, 6)`.
+    pub fn get_statics(&self) -> HashMap<String, Literal> {
+        self.generator()
+    }
+
+    pub fn get_fields(&self) -> HashMap<String, InstanceField> {
+        HashMap::new()
+    }
+
+    fn generator(&self) -> HashMap<String, Literal> {
+        let mut map: HashMap<String, Literal> = HashMap::new();
+        map.insert(String::from("next"), next::Next::new(self.rng.clone()));
+        map.insert(String::from("int"), int::Int::new(self.rng.clone()));
+        map.insert(String::from("float"), float::Float::new(self.rng.clone()));
+        map.insert(
+            String::from("choice"),
+            choice::Choice::new(self.rng.clone()),
+        );
+        map.insert(
+            String::from("shuffle"),
+            shuffle::Shuffle::new(self.rng.clone()),
+        );
+        map.insert(
+            String::from("sample"),
+            sample::Sample::new(self.rng.clone()),
+        );
+        map
+    }
+}
@@ -51,6 +51,6 @@ impl LoxString {
     }
 
     pub fn get(&self, name: &Token) -> Result<Literal, LoxResult> {
-        return self.str_inst.get(name, &self.str_inst)
+        self.str_inst.get(name, &self.str_inst, false)
     }
 }
@@ -0,0 +1,57 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{
+        array::loxarray::LoxArray, loxnatives::LoxNative, number::loxnumber::LoxNumber,
+        string::StringData,
+    },
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Bytes {
+    string: StringData,
+}
+
+impl Bytes {
+    pub fn new(string: StringData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new(
+            "bytes",
+            Rc::new(Self { string }),
+            true,
+        )))
+    }
+}
+
+impl LoxCallable for Bytes {
+    fn arity(&self) -> usize {
+        0
+    }
+
+    fn call(&self, _: Option<&Interpreter>, _: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let arr = self
+            .string
+            .borrow()
+            .bytes()
+            .map(|b| Literal::Number(Rc::new(LoxNumber::new(b as f64))))
+            .collect();
+        Ok(Literal::Array(Rc::new(LoxArray::new(arr))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn bytes_utf8() {
+        let bytes = Bytes {
+            string: string("aé"),
+        };
+        let result = bytes.call(None, Vec::new()).unwrap();
+        assert_eq!(result.to_string(), "[97, 195, 169]");
+    }
+}
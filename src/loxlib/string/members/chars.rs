@@ -0,0 +1,58 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{
+        array::loxarray::LoxArray,
+        loxnatives::LoxNative,
+        string::{loxstring::LoxString, StringData},
+    },
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Chars {
+    string: StringData,
+}
+
+impl Chars {
+    pub fn new(string: StringData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new(
+            "chars",
+            Rc::new(Self { string }),
+            true,
+        )))
+    }
+}
+
+impl LoxCallable for Chars {
+    fn arity(&self) -> usize {
+        0
+    }
+
+    fn call(&self, _: Option<&Interpreter>, _: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let arr = self
+            .string
+            .borrow()
+            .chars()
+            .map(|c| Literal::Str(Rc::new(LoxString::new(c.to_string()))))
+            .collect();
+        Ok(Literal::Array(Rc::new(LoxArray::new(arr))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn chars_non_ascii() {
+        let chars = Chars {
+            string: string("añ€😀"),
+        };
+        let result = chars.call(None, Vec::new()).unwrap();
+        assert_eq!(result.to_string(), r#"["a", "ñ", "€", "😀"]"#);
+    }
+}
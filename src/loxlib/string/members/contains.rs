@@ -0,0 +1,58 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{
+        loxnatives::LoxNative,
+        string::StringData,
+    },
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Contains {
+    string: StringData,
+}
+
+impl Contains {
+    pub fn new(string: StringData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new(
+            "contains",
+            Rc::new(Self { string }),
+            true,
+        )))
+    }
+}
+
+impl LoxCallable for Contains {
+    fn arity(&self) -> usize {
+        1
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let arg = args.first().unwrap();
+        if arg.get_typename() != "String" {
+            return Err(LoxResult::Message(format!(
+                "Expected type 'String' for 'pattern' in Str.contains(pattern) got '{}'",
+                arg.get_typename()
+            )));
+        }
+
+        Ok(Literal::Bool(self.string.borrow().contains(&arg.unwrap_str())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn contains() {
+        let contains = Contains { string: string("añb") };
+        assert_eq!(contains.call(None, vec![lit("ñ")]).unwrap(), Literal::Bool(true));
+        assert_eq!(contains.call(None, vec![lit("x")]).unwrap(), Literal::Bool(false));
+        assert!(contains.call(None, vec![Literal::Bool(true)]).is_err());
+    }
+}
@@ -0,0 +1,67 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{
+        loxnatives::LoxNative,
+        number::loxnumber::LoxNumber,
+        string::StringData,
+    },
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Count {
+    string: StringData,
+}
+
+impl Count {
+    pub fn new(string: StringData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new(
+            "count",
+            Rc::new(Self { string }),
+            true,
+        )))
+    }
+}
+
+impl LoxCallable for Count {
+    fn arity(&self) -> usize {
+        1
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let arg = args.first().unwrap();
+        if arg.get_typename() != "String" {
+            return Err(LoxResult::Message(format!(
+                "Expected type 'String' for 'pattern' in Str.count(pattern) got '{}'",
+                arg.get_typename()
+            )));
+        }
+        let pattern = arg.unwrap_str();
+        if pattern.is_empty() {
+            return Err(LoxResult::Message(
+                "Expected 'pattern' to not be empty in Str.count(pattern)".to_string(),
+            ));
+        }
+
+        // Occurrences don't overlap, "aaa".count("aa") is 1
+        let count = self.string.borrow().matches(&pattern).count();
+        Ok(Literal::Number(Rc::new(LoxNumber::new(count as f64))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn count() {
+        let count = Count { string: string("ñaña aaa") };
+        assert_eq!(count.call(None, vec![lit("ñ")]).unwrap().unwrap_number(), 2.0);
+        assert_eq!(count.call(None, vec![lit("aa")]).unwrap().unwrap_number(), 1.0);
+        assert!(count.call(None, vec![lit("")]).is_err());
+    }
+}
@@ -0,0 +1,58 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{
+        loxnatives::LoxNative,
+        string::StringData,
+    },
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct EndsWith {
+    string: StringData,
+}
+
+impl EndsWith {
+    pub fn new(string: StringData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new(
+            "ends_with",
+            Rc::new(Self { string }),
+            true,
+        )))
+    }
+}
+
+impl LoxCallable for EndsWith {
+    fn arity(&self) -> usize {
+        1
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let arg = args.first().unwrap();
+        if arg.get_typename() != "String" {
+            return Err(LoxResult::Message(format!(
+                "Expected type 'String' for 'pattern' in Str.ends_with(pattern) got '{}'",
+                arg.get_typename()
+            )));
+        }
+
+        Ok(Literal::Bool(self.string.borrow().ends_with(&arg.unwrap_str())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn ends_with() {
+        let ends_with = EndsWith { string: string("añb") };
+        assert_eq!(ends_with.call(None, vec![lit("ñb")]).unwrap(), Literal::Bool(true));
+        assert_eq!(ends_with.call(None, vec![lit("a")]).unwrap(), Literal::Bool(false));
+        assert!(ends_with.call(None, vec![Literal::Bool(true)]).is_err());
+    }
+}
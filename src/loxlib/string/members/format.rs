@@ -0,0 +1,69 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{
+        format::format_string,
+        loxnatives::LoxNative,
+        string::{loxstring::LoxString, StringData},
+    },
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Format {
+    string: StringData,
+}
+
+impl Format {
+    pub fn new(string: StringData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new(
+            "format",
+            Rc::new(Self { string }),
+            false,
+        )))
+    }
+}
+
+impl LoxCallable for Format {
+    fn arity(&self) -> usize {
+        0
+    }
+
+    fn call(&self, interpreter: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        self.call_keywords(interpreter, args, Vec::new())
+    }
+
+    fn accepts_keywords(&self) -> bool {
+        true
+    }
+
+    fn call_keywords(
+        &self,
+        interpreter: Option<&Interpreter>,
+        args: Vec<Literal>,
+        keywords: Vec<(String, Literal)>,
+    ) -> Result<Literal, LoxResult> {
+        let interpreter = interpreter.expect("Interpreter was not provided for Str.format");
+        let template = self.string.borrow().to_string();
+        let string = format_string(interpreter, &template, &args, &keywords)?;
+        Ok(Literal::Str(Rc::new(LoxString::new(string))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn format() {
+        let interpreter = Interpreter::new();
+        let format = Format { string: string("{:>5.1}|{n}") };
+        let args = vec![num(2.25)];
+        let keywords = vec![("n".to_string(), Literal::Bool(true))];
+        let result = format.call_keywords(Some(&interpreter), args, keywords).unwrap();
+        assert_eq!(result.unwrap_str(), "  2.2|true");
+    }
+}
@@ -0,0 +1,64 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{
+        loxnatives::LoxNative,
+        number::loxnumber::LoxNumber,
+        string::StringData,
+    },
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct IndexOf {
+    string: StringData,
+}
+
+impl IndexOf {
+    pub fn new(string: StringData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new(
+            "index_of",
+            Rc::new(Self { string }),
+            true,
+        )))
+    }
+}
+
+impl LoxCallable for IndexOf {
+    fn arity(&self) -> usize {
+        1
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let arg = args.first().unwrap();
+        if arg.get_typename() != "String" {
+            return Err(LoxResult::Message(format!(
+                "Expected type 'String' for 'pattern' in Str.index_of(pattern) got '{}'",
+                arg.get_typename()
+            )));
+        }
+
+        // Positions count characters, not bytes
+        let string = self.string.borrow();
+        let index = match string.find(&arg.unwrap_str()) {
+            Some(byte) => string[..byte].chars().count() as f64,
+            None => -1.0,
+        };
+        Ok(Literal::Number(Rc::new(LoxNumber::new(index))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn index_of() {
+        let index_of = IndexOf { string: string("ñandú") };
+        assert_eq!(index_of.call(None, vec![lit("n")]).unwrap().unwrap_number(), 2.0);
+        assert_eq!(index_of.call(None, vec![lit("x")]).unwrap().unwrap_number(), -1.0);
+    }
+}
@@ -21,8 +21,12 @@ impl LoxCallable for Init {
         1
     }
 
-    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
-        let string = args.get(0).unwrap().get_value();
+    fn call(&self, interpreter: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let arg = args.get(0).unwrap();
+        let string = match interpreter {
+            Some(interpreter) => interpreter.stringify(arg)?,
+            None => arg.get_value(),
+        };
         Ok(Literal::Str(Rc::new(LoxString::new(string))))
     }
 }
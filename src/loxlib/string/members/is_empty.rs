@@ -0,0 +1,50 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{
+        loxnatives::LoxNative,
+        string::StringData,
+    },
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct IsEmpty {
+    string: StringData,
+}
+
+impl IsEmpty {
+    pub fn new(string: StringData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new(
+            "is_empty",
+            Rc::new(Self { string }),
+            true,
+        )))
+    }
+}
+
+impl LoxCallable for IsEmpty {
+    fn arity(&self) -> usize {
+        0
+    }
+
+    fn call(&self, _: Option<&Interpreter>, _: Vec<Literal>) -> Result<Literal, LoxResult> {
+        Ok(Literal::Bool(self.string.borrow().is_empty()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn is_empty() {
+        let empty = IsEmpty { string: string("") };
+        let not_empty = IsEmpty { string: string(" ") };
+        assert_eq!(empty.call(None, Vec::new()).unwrap(), Literal::Bool(true));
+        assert_eq!(not_empty.call(None, Vec::new()).unwrap(), Literal::Bool(false));
+    }
+}
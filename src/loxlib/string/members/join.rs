@@ -0,0 +1,68 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{
+        loxnatives::LoxNative,
+        string::loxstring::LoxString,
+    },
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Join;
+
+impl Join {
+    pub fn new() -> Literal {
+        Literal::Native(Rc::new(LoxNative::new("join", Rc::new(Self {}), true)))
+    }
+}
+
+impl LoxCallable for Join {
+    fn arity(&self) -> usize {
+        2
+    }
+
+    fn call(&self, interpreter: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let interpreter = interpreter.expect("Interpreter was not provided for Str.join");
+        let arr = match args.first().unwrap() {
+            Literal::Array(arr) => arr.array.borrow().clone(),
+            arg => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'Array' for 'arr' in Str.join(arr, sep) got '{}'",
+                    arg.get_typename()
+                )))
+            }
+        };
+        let sep = args.get(1).unwrap();
+        if sep.get_typename() != "String" {
+            return Err(LoxResult::Message(format!(
+                "Expected type 'String' for 'sep' in Str.join(arr, sep) got '{}'",
+                sep.get_typename()
+            )));
+        }
+
+        let mut parts = Vec::with_capacity(arr.len());
+        for el in arr.iter() {
+            parts.push(interpreter.stringify(el)?);
+        }
+        Ok(Literal::Str(Rc::new(LoxString::new(parts.join(&sep.unwrap_str())))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+    use crate::loxlib::array::loxarray::LoxArray;
+
+    #[test]
+    fn join() {
+        let interpreter = Interpreter::new();
+        let arr = Literal::Array(Rc::new(LoxArray::new(vec![lit("a"), lit("ñ")])));
+        let result = Join.call(Some(&interpreter), vec![arr, lit(", ")]).unwrap();
+        assert_eq!(result.unwrap_str(), "a, ñ");
+        assert!(Join.call(Some(&interpreter), vec![lit("a"), lit(", ")]).is_err());
+    }
+}
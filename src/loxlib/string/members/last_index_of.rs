@@ -0,0 +1,63 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{
+        loxnatives::LoxNative,
+        number::loxnumber::LoxNumber,
+        string::StringData,
+    },
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct LastIndexOf {
+    string: StringData,
+}
+
+impl LastIndexOf {
+    pub fn new(string: StringData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new(
+            "last_index_of",
+            Rc::new(Self { string }),
+            true,
+        )))
+    }
+}
+
+impl LoxCallable for LastIndexOf {
+    fn arity(&self) -> usize {
+        1
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let arg = args.first().unwrap();
+        if arg.get_typename() != "String" {
+            return Err(LoxResult::Message(format!(
+                "Expected type 'String' for 'pattern' in Str.last_index_of(pattern) got '{}'",
+                arg.get_typename()
+            )));
+        }
+
+        let string = self.string.borrow();
+        let index = match string.rfind(&arg.unwrap_str()) {
+            Some(byte) => string[..byte].chars().count() as f64,
+            None => -1.0,
+        };
+        Ok(Literal::Number(Rc::new(LoxNumber::new(index))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn last_index_of() {
+        let last_index_of = LastIndexOf { string: string("ñaña") };
+        assert_eq!(last_index_of.call(None, vec![lit("a")]).unwrap().unwrap_number(), 3.0);
+        assert_eq!(last_index_of.call(None, vec![lit("x")]).unwrap().unwrap_number(), -1.0);
+    }
+}
@@ -24,6 +24,6 @@ impl LoxCallable for Len {
     }
 
     fn call(&self, _: Option<&Interpreter>, _: Vec<Literal>) -> Result<Literal, LoxResult> {
-        Ok(Literal::Number(Rc::new(LoxNumber::new(self.string.borrow().len() as f64))))
+        Ok(Literal::Number(Rc::new(LoxNumber::new(self.string.borrow().chars().count() as f64))))
     }
 }
@@ -0,0 +1,56 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{
+        array::loxarray::LoxArray,
+        loxnatives::LoxNative,
+        string::{loxstring::LoxString, StringData},
+    },
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Lines {
+    string: StringData,
+}
+
+impl Lines {
+    pub fn new(string: StringData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new(
+            "lines",
+            Rc::new(Self { string }),
+            true,
+        )))
+    }
+}
+
+impl LoxCallable for Lines {
+    fn arity(&self) -> usize {
+        0
+    }
+
+    fn call(&self, _: Option<&Interpreter>, _: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let arr = self
+            .string
+            .borrow()
+            .lines()
+            .map(|line| Literal::Str(Rc::new(LoxString::new(line.to_string()))))
+            .collect();
+        Ok(Literal::Array(Rc::new(LoxArray::new(arr))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn lines() {
+        let lines = Lines { string: string("one\r\ntwo\n\nthree") };
+        let result = lines.call(None, Vec::new()).unwrap();
+        assert_eq!(result.to_string(), r#"["one", "two", "", "three"]"#);
+    }
+}
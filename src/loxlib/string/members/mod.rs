@@ -9,3 +9,44 @@ pub mod trim;
 pub mod trim_end;
 pub mod trim_start;
 pub mod split;
+pub mod chars;
+pub mod bytes;
+pub mod contains;
+pub mod starts_with;
+pub mod ends_with;
+pub mod index_of;
+pub mod last_index_of;
+pub mod repeat;
+pub mod pad_start;
+pub mod pad_end;
+pub mod lines;
+pub mod reverse;
+pub mod is_empty;
+pub mod count;
+pub mod join;
+pub mod format;
+
+#[cfg(test)]
+pub mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use crate::{
+        lexer::literal::Literal,
+        loxlib::{
+            number::loxnumber::LoxNumber,
+            string::{loxstring::LoxString, StringData},
+        },
+    };
+
+    pub fn string(s: &str) -> StringData {
+        Rc::new(RefCell::new(s.to_string()))
+    }
+
+    pub fn lit(s: &str) -> Literal {
+        Literal::Str(Rc::new(LoxString::new(s.to_string())))
+    }
+
+    pub fn num(n: f64) -> Literal {
+        Literal::Number(Rc::new(LoxNumber::new(n)))
+    }
+}
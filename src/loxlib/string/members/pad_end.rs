@@ -0,0 +1,106 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{
+        loxnatives::LoxNative,
+        string::{loxstring::LoxString, StringData},
+        MAX_LENGTH,
+    },
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct PadEnd {
+    string: StringData,
+}
+
+impl PadEnd {
+    pub fn new(string: StringData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new(
+            "pad_end",
+            Rc::new(Self { string }),
+            false,
+        )))
+    }
+}
+
+impl LoxCallable for PadEnd {
+    fn arity(&self) -> usize {
+        2
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        if args.is_empty() || args.len() > 2 {
+            return Err(LoxResult::Message(format!(
+                "Expected 1 to 2 arguments but got {} for 'pad_end'",
+                args.len()
+            )));
+        }
+        let len = &args[0];
+        if len.get_typename() != "Number" {
+            return Err(LoxResult::Message(format!(
+                "Expected type 'Number' for 'len' in Str.pad_end(len, fill) got '{}'",
+                len.get_typename()
+            )));
+        }
+        // Also rejects NaN, infinity and fractions, shorter lengths leave the string as is
+        let len = len.unwrap_number();
+        if len.is_nan() || len.fract() != 0.0 {
+            return Err(LoxResult::Message(format!(
+                "Expected 'len' to be an integer in Str.pad_end(len, fill) got {}",
+                len
+            )));
+        }
+        if len > MAX_LENGTH as f64 {
+            return Err(LoxResult::Message(format!(
+                "Expected 'len' to be at most {} in Str.pad_end(len, fill) got {}",
+                MAX_LENGTH, len
+            )));
+        }
+        let fill = match args.get(1) {
+            Some(Literal::Str(fill)) => fill.string.borrow().to_string(),
+            Some(fill) => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'String' for 'fill' in Str.pad_end(len, fill) got '{}'",
+                    fill.get_typename()
+                )))
+            }
+            None => " ".to_string(),
+        };
+        if fill.is_empty() {
+            return Err(LoxResult::Message(
+                "Expected 'fill' to not be empty in Str.pad_end(len, fill)".to_string(),
+            ));
+        }
+
+        let string = self.string.borrow();
+        let missing = (len.max(0.0) as usize).saturating_sub(string.chars().count());
+        let padding: String = fill.chars().cycle().take(missing).collect();
+        Ok(Literal::Str(Rc::new(LoxString::new(string.to_string() + &padding))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn pad_end() {
+        let pad = PadEnd { string: string("ñ") };
+        assert_eq!(pad.call(None, vec![num(4.0), lit("ab")]).unwrap().unwrap_str(), "ñaba");
+        assert_eq!(pad.call(None, vec![num(2.0)]).unwrap().unwrap_str(), "ñ ");
+    }
+
+    #[test]
+    fn pad_end_invalid() {
+        let pad = PadEnd { string: string("ab") };
+        for len in [f64::INFINITY, f64::NEG_INFINITY, f64::NAN, 2.5, (MAX_LENGTH + 1) as f64] {
+            let result = pad.call(None, vec![num(len), lit("x")]);
+            assert!(result.is_err(), "Str.pad_end accepted {}", len);
+        }
+        assert_eq!(pad.call(None, vec![num(-3.0)]).unwrap().unwrap_str(), "ab");
+    }
+}
@@ -0,0 +1,109 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{
+        loxnatives::LoxNative,
+        string::{loxstring::LoxString, StringData},
+        MAX_LENGTH,
+    },
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct PadStart {
+    string: StringData,
+}
+
+impl PadStart {
+    pub fn new(string: StringData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new(
+            "pad_start",
+            Rc::new(Self { string }),
+            false,
+        )))
+    }
+}
+
+impl LoxCallable for PadStart {
+    fn arity(&self) -> usize {
+        2
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        if args.is_empty() || args.len() > 2 {
+            return Err(LoxResult::Message(format!(
+                "Expected 1 to 2 arguments but got {} for 'pad_start'",
+                args.len()
+            )));
+        }
+        let len = &args[0];
+        if len.get_typename() != "Number" {
+            return Err(LoxResult::Message(format!(
+                "Expected type 'Number' for 'len' in Str.pad_start(len, fill) got '{}'",
+                len.get_typename()
+            )));
+        }
+        // Also rejects NaN, infinity and fractions, shorter lengths leave the string as is
+        let len = len.unwrap_number();
+        if len.is_nan() || len.fract() != 0.0 {
+            return Err(LoxResult::Message(format!(
+                "Expected 'len' to be an integer in Str.pad_start(len, fill) got {}",
+                len
+            )));
+        }
+        if len > MAX_LENGTH as f64 {
+            return Err(LoxResult::Message(format!(
+                "Expected 'len' to be at most {} in Str.pad_start(len, fill) got {}",
+                MAX_LENGTH, len
+            )));
+        }
+        let fill = match args.get(1) {
+            Some(Literal::Str(fill)) => fill.string.borrow().to_string(),
+            Some(fill) => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'String' for 'fill' in Str.pad_start(len, fill) got '{}'",
+                    fill.get_typename()
+                )))
+            }
+            None => " ".to_string(),
+        };
+        if fill.is_empty() {
+            return Err(LoxResult::Message(
+                "Expected 'fill' to not be empty in Str.pad_start(len, fill)".to_string(),
+            ));
+        }
+
+        // Lengths count characters, the fill is cut short to fit exactly
+        let string = self.string.borrow();
+        let missing = (len.max(0.0) as usize).saturating_sub(string.chars().count());
+        let padding: String = fill.chars().cycle().take(missing).collect();
+        Ok(Literal::Str(Rc::new(LoxString::new(padding + &string))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn pad_start() {
+        let pad = PadStart { string: string("ñ") };
+        assert_eq!(pad.call(None, vec![num(4.0), lit("ab")]).unwrap().unwrap_str(), "abañ");
+        assert_eq!(pad.call(None, vec![num(3.0)]).unwrap().unwrap_str(), "  ñ");
+        assert_eq!(pad.call(None, vec![num(0.0)]).unwrap().unwrap_str(), "ñ");
+        assert!(pad.call(None, vec![num(3.0), lit("")]).is_err());
+    }
+
+    #[test]
+    fn pad_start_invalid() {
+        let pad = PadStart { string: string("ab") };
+        for len in [f64::INFINITY, f64::NEG_INFINITY, f64::NAN, 2.5, (MAX_LENGTH + 1) as f64] {
+            let result = pad.call(None, vec![num(len), lit("x")]);
+            assert!(result.is_err(), "Str.pad_start accepted {}", len);
+        }
+        assert_eq!(pad.call(None, vec![num(-3.0)]).unwrap().unwrap_str(), "ab");
+    }
+}
@@ -0,0 +1,86 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{
+        loxnatives::LoxNative,
+        string::{loxstring::LoxString, StringData},
+        MAX_LENGTH,
+    },
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Repeat {
+    string: StringData,
+}
+
+impl Repeat {
+    pub fn new(string: StringData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new(
+            "repeat",
+            Rc::new(Self { string }),
+            true,
+        )))
+    }
+}
+
+impl LoxCallable for Repeat {
+    fn arity(&self) -> usize {
+        1
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let n = args.first().unwrap();
+        if n.get_typename() != "Number" {
+            return Err(LoxResult::Message(format!(
+                "Expected type 'Number' for 'n' in Str.repeat(n) got '{}'",
+                n.get_typename()
+            )));
+        }
+        // Also rejects NaN, infinity and fractions
+        let n = n.unwrap_number();
+        if n.is_nan() || n < 0.0 || n.fract() != 0.0 {
+            return Err(LoxResult::Message(format!(
+                "Expected 'n' to be a non negative integer in Str.repeat(n) got {}",
+                n
+            )));
+        }
+        let string = self.string.borrow();
+        if string.chars().count() as f64 * n > MAX_LENGTH as f64 {
+            return Err(LoxResult::Message(format!(
+                "Expected the result to be at most {} characters in Str.repeat(n)",
+                MAX_LENGTH
+            )));
+        }
+        let string = string.repeat(n as usize);
+        Ok(Literal::Str(Rc::new(LoxString::new(string))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn repeat() {
+        let repeat = Repeat { string: string("ab") };
+        assert_eq!(repeat.call(None, vec![num(3.0)]).unwrap().unwrap_str(), "ababab");
+        assert!(repeat.call(None, vec![num(-1.0)]).is_err());
+        assert_eq!(repeat.call(None, vec![num(0.0)]).unwrap().unwrap_str(), "");
+    }
+
+    #[test]
+    fn repeat_invalid() {
+        let repeat = Repeat { string: string("ab") };
+        for n in [f64::INFINITY, f64::NEG_INFINITY, f64::NAN, 1.5, -0.5] {
+            assert!(repeat.call(None, vec![num(n)]).is_err(), "Str.repeat accepted {}", n);
+        }
+        let n = (MAX_LENGTH / 2 + 1) as f64;
+        assert!(repeat.call(None, vec![num(n)]).is_err());
+        let empty = Repeat { string: string("") };
+        assert_eq!(empty.call(None, vec![num(1e300)]).unwrap().unwrap_str(), "");
+    }
+}
@@ -0,0 +1,49 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{
+        loxnatives::LoxNative,
+        string::{loxstring::LoxString, StringData},
+    },
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct Reverse {
+    string: StringData,
+}
+
+impl Reverse {
+    pub fn new(string: StringData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new(
+            "reverse",
+            Rc::new(Self { string }),
+            true,
+        )))
+    }
+}
+
+impl LoxCallable for Reverse {
+    fn arity(&self) -> usize {
+        0
+    }
+
+    fn call(&self, _: Option<&Interpreter>, _: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let string = self.string.borrow().chars().rev().collect();
+        Ok(Literal::Str(Rc::new(LoxString::new(string))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn reverse() {
+        let reverse = Reverse { string: string("añ€") };
+        assert_eq!(reverse.call(None, Vec::new()).unwrap().unwrap_str(), "€ña");
+    }
+}
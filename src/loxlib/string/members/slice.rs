@@ -47,19 +47,16 @@ impl LoxCallable for Slice {
             )));
         }
 
+        // Positions count characters, slicing by bytes could split a multi-byte character
         let start = start.unwrap_number() as usize;
         let end = end.unwrap_number() as usize;
-        let len = self.string.borrow().len();
-
-        if end <= start || start > len {
-            return Ok(Literal::Str(Rc::new(LoxString::new(String::from("")))));
-        }
-
-        if start < len && end > len {
-            let str = &self.string.borrow()[start..len];
-            return Ok(Literal::Str(Rc::new(LoxString::new(str.to_string()))));
-        }
-        let str = &self.string.borrow()[start..end];
-        Ok(Literal::Str(Rc::new(LoxString::new(str.to_string()))))
+        let str: String = self
+            .string
+            .borrow()
+            .chars()
+            .skip(start)
+            .take(end.saturating_sub(start))
+            .collect();
+        Ok(Literal::Str(Rc::new(LoxString::new(str))))
     }
 }
@@ -0,0 +1,58 @@
+use std::rc::Rc;
+
+use crate::{
+    error::*,
+    lexer::literal::Literal,
+    loxlib::{
+        loxnatives::LoxNative,
+        string::StringData,
+    },
+    runtime::callable::LoxCallable,
+    runtime::interpreter::Interpreter,
+};
+
+pub struct StartsWith {
+    string: StringData,
+}
+
+impl StartsWith {
+    pub fn new(string: StringData) -> Literal {
+        Literal::Native(Rc::new(LoxNative::new(
+            "starts_with",
+            Rc::new(Self { string }),
+            true,
+        )))
+    }
+}
+
+impl LoxCallable for StartsWith {
+    fn arity(&self) -> usize {
+        1
+    }
+
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let arg = args.first().unwrap();
+        if arg.get_typename() != "String" {
+            return Err(LoxResult::Message(format!(
+                "Expected type 'String' for 'pattern' in Str.starts_with(pattern) got '{}'",
+                arg.get_typename()
+            )));
+        }
+
+        Ok(Literal::Bool(self.string.borrow().starts_with(&arg.unwrap_str())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn starts_with() {
+        let starts_with = StartsWith { string: string("añb") };
+        assert_eq!(starts_with.call(None, vec![lit("añ")]).unwrap(), Literal::Bool(true));
+        assert_eq!(starts_with.call(None, vec![lit("b")]).unwrap(), Literal::Bool(false));
+        assert!(starts_with.call(None, vec![Literal::Bool(true)]).is_err());
+    }
+}
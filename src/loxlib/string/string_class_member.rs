@@ -1,6 +1,10 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, rc::Rc};
 
-use crate::{lexer::literal::Literal, runtime::loxinstance::InstanceField};
+use crate::{
+    lexer::literal::Literal,
+    loxlib::{format::Format, loxnatives::LoxNative},
+    runtime::loxinstance::InstanceField,
+};
 use super::{members::*, StringData};
 
 pub struct StringMembers {
@@ -27,11 +31,33 @@ impl StringMembers {
         map.insert(String::from("trim_start"), trim_start::TrimStart::new(self.string.clone()));
         map.insert(String::from("trim_end"), trim_end::TrimEnd::new(self.string.clone()));
         map.insert(String::from("split"), split::Split::new(self.string.clone()));
+        map.insert(String::from("chars"), chars::Chars::new(self.string.clone()));
+        map.insert(String::from("bytes"), bytes::Bytes::new(self.string.clone()));
+        map.insert(String::from("contains"), contains::Contains::new(self.string.clone()));
+        map.insert(String::from("starts_with"), starts_with::StartsWith::new(self.string.clone()));
+        map.insert(String::from("ends_with"), ends_with::EndsWith::new(self.string.clone()));
+        map.insert(String::from("index_of"), index_of::IndexOf::new(self.string.clone()));
+        map.insert(String::from("last_index_of"), last_index_of::LastIndexOf::new(self.string.clone()));
+        map.insert(String::from("repeat"), repeat::Repeat::new(self.string.clone()));
+        map.insert(String::from("pad_start"), pad_start::PadStart::new(self.string.clone()));
+        map.insert(String::from("pad_end"), pad_end::PadEnd::new(self.string.clone()));
+        map.insert(String::from("lines"), lines::Lines::new(self.string.clone()));
+        map.insert(String::from("reverse"), reverse::Reverse::new(self.string.clone()));
+        map.insert(String::from("is_empty"), is_empty::IsEmpty::new(self.string.clone()));
+        map.insert(String::from("count"), count::Count::new(self.string.clone()));
+        map.insert(String::from("format"), format::Format::new(self.string.clone()));
         map
     }
 
     pub fn get_statics(&self) -> HashMap<String, Literal> {
-        HashMap::new()
+        let mut map: HashMap<String, Literal> = HashMap::new();
+        map.insert(String::from("join"), join::Join::new());
+        // Same as the `format` native, the template is the first argument
+        map.insert(
+            String::from("format"),
+            Literal::Native(Rc::new(LoxNative::new("format", Rc::new(Format {}), false))),
+        );
+        map
     }
 
     pub fn get_fields(&self) -> HashMap<String, InstanceField> {
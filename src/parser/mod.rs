@@ -1,3 +1,4 @@
 pub mod expr;
+pub mod pattern;
 pub mod rdp;
 pub mod stmt;
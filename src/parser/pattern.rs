@@ -0,0 +1,153 @@
+use std::fmt::Display;
+use std::rc::Rc;
+
+use super::{expr::Expr, stmt::Stmt};
+use crate::lexer::{literal::Literal, token::Token};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pattern {
+    Wildcard(Token),
+    Binding(Token),
+    Literal(Token, Literal),
+    // `1..5` excludes the end, `1..=5` includes it
+    Range {
+        start: Literal,
+        end: Literal,
+        inclusive: bool,
+    },
+    Array(Token, Vec<Pattern>),
+    // `enum_expr` is resolved like any other variable, `fields` is `None` for
+    // `Shape.Circle` which matches the variant whatever it carries.
+    Variant {
+        enum_expr: Rc<Expr>,
+        name: Token,
+        fields: Option<Vec<Pattern>>,
+    },
+    Or(Vec<Pattern>),
+}
+
+impl Pattern {
+    // Names bound by the pattern, in order of appearance.
+    pub fn bindings(&self) -> Vec<Token> {
+        match self {
+            Pattern::Binding(name) => vec![name.dup()],
+            Pattern::Array(_, patterns) | Pattern::Or(patterns) => {
+                patterns.iter().flat_map(|p| p.bindings()).collect()
+            }
+            Pattern::Variant {
+                fields: Some(fields),
+                ..
+            } => fields.iter().flat_map(|p| p.bindings()).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    // True when the pattern matches every value.
+    pub fn is_irrefutable(&self) -> bool {
+        match self {
+            Pattern::Wildcard(_) | Pattern::Binding(_) => true,
+            Pattern::Or(patterns) => patterns.iter().any(|p| p.is_irrefutable()),
+            _ => false,
+        }
+    }
+}
+
+impl Display for Pattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let join = |patterns: &Vec<Pattern>, sep: &str| {
+            patterns
+                .iter()
+                .map(|p| p.to_string())
+                .collect::<Vec<String>>()
+                .join(sep)
+        };
+        match self {
+            Pattern::Wildcard(_) => write!(f, "_"),
+            Pattern::Binding(name) => write!(f, "{}", name.lexeme),
+            Pattern::Literal(_, value) => write!(f, "{}", value.repr()),
+            Pattern::Range {
+                start,
+                end,
+                inclusive,
+            } => {
+                let op = if *inclusive { "..=" } else { ".." };
+                write!(f, "{}{op}{}", start.repr(), end.repr())
+            }
+            Pattern::Array(_, patterns) => write!(f, "[{}]", join(patterns, ", ")),
+            Pattern::Variant {
+                enum_expr,
+                name,
+                fields,
+            } => {
+                if let Expr::Variable(v) = &**enum_expr {
+                    write!(f, "{}.", v.name.lexeme)?;
+                }
+                match fields {
+                    Some(fields) => write!(f, "{}({})", name.lexeme, join(fields, ", ")),
+                    None => write!(f, "{}", name.lexeme),
+                }
+            }
+            Pattern::Or(patterns) => write!(f, "{}", join(patterns, " | ")),
+        }
+    }
+}
+
+// Left hand side of a destructuring `let`, also used for parameters.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Destructure {
+    // `[a, b, ...rest]`, `rest` takes the remaining elements as an array
+    Array {
+        bracket: Token,
+        names: Vec<Token>,
+        rest: Option<Token>,
+    },
+    // `{ name, age }`, reads the properties of the same name
+    Object { brace: Token, names: Vec<Token> },
+}
+
+impl Destructure {
+    pub fn bindings(&self) -> Vec<Token> {
+        match self {
+            Destructure::Array { names, rest, .. } => {
+                names.iter().chain(rest.iter()).map(|n| n.dup()).collect()
+            }
+            Destructure::Object { names, .. } => names.iter().map(|n| n.dup()).collect(),
+        }
+    }
+
+    pub fn token(&self) -> &Token {
+        match self {
+            Destructure::Array { bracket, .. } => bracket,
+            Destructure::Object { brace, .. } => brace,
+        }
+    }
+}
+
+impl Display for Destructure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let join = |names: &Vec<Token>| {
+            names
+                .iter()
+                .map(|n| n.lexeme.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+        match self {
+            Destructure::Array { names, rest, .. } => match rest {
+                Some(rest) if names.is_empty() => write!(f, "[...{}]", rest.lexeme),
+                Some(rest) => write!(f, "[{}, ...{}]", join(names), rest.lexeme),
+                None => write!(f, "[{}]", join(names)),
+            },
+            Destructure::Object { names, .. } => write!(f, "{{ {} }}", join(names)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchArm {
+    pub pattern: Pattern,
+    pub guard: Option<Rc<Expr>>,
+    pub arrow: Token,
+    // An expression statement yields the value of the arm, a block yields `none`
+    pub body: Rc<Stmt>,
+}
@@ -4,17 +4,43 @@ use crate::{
     lexer::token::Token,
     lexer::tokentype::TokenType,
     loxlib::string::loxstring::LoxString,
+    loxlib::number::loxnumber::LoxNumber,
     parser::expr::{BinaryExpr, Expr, GroupingExpr, LiteralExpr, UnaryExpr},
 };
 use std::rc::Rc;
 
-use super::{expr::*, stmt::*};
+use super::{
+    expr::*,
+    pattern::{Destructure, MatchArm, Pattern},
+    stmt::*,
+};
+
+// Members collected while parsing a class body.
+#[derive(Default)]
+struct ClassMembers {
+    is_abstract: bool,
+    fields: Vec<Rc<Stmt>>,
+    methods: Vec<Rc<Stmt>>,
+    getters: Vec<Rc<Stmt>>,
+    setters: Vec<Rc<Stmt>>,
+}
+
+// Parameter list of a function or lambda, defaults belong to the trailing
+// parameters before the rest parameter.
+#[derive(Default)]
+struct Params {
+    names: Vec<Token>,
+    defaults: Vec<Rc<Expr>>,
+    // The last parameter collects the remaining arguments in an array
+    is_variadic: bool,
+}
 
 pub struct Parser<'a> {
     tokens: &'a Vec<Token>,
     curr: usize,
     error_handler: LoxErrorHandler,
     current_token: Option<Token>,
+    is_repl: bool,
 }
 
 impl<'a> Parser<'a> {
@@ -24,9 +50,14 @@ impl<'a> Parser<'a> {
             curr: 0,
             error_handler: LoxErrorHandler::new(),
             current_token: None,
+            is_repl: false,
         }
     }
 
+    pub fn set_is_repl(&mut self, is: bool) {
+        self.is_repl = is;
+    }
+
     pub fn parse(&mut self) -> Result<Vec<Rc<Stmt>>, LoxResult> {
         let mut statments: Vec<Rc<Stmt>> = Vec::new();
         while !self.is_at_end() {
@@ -37,6 +68,23 @@ impl<'a> Parser<'a> {
     }
 
     fn var_declaration(&mut self) -> Result<Rc<Stmt>, LoxResult> {
+        if self.check(TokenType::LeftBracket) || self.check(TokenType::LeftBrace) {
+            let target = self.destructure()?;
+            self.consume(
+                TokenType::Assign,
+                LoxErrorsTypes::Syntax("Expected '=' after destructuring pattern".to_string()),
+            )?;
+            let initializer = self.expression()?;
+            self.consume(
+                TokenType::Semicolon,
+                LoxErrorsTypes::Syntax("Expect ';' after".to_string()),
+            )?;
+            return Ok(Rc::new(Stmt::Destructure(DestructureStmt::new(
+                target,
+                initializer,
+            ))));
+        }
+
         let name = self.consume(
             TokenType::Identifier,
             LoxErrorsTypes::Syntax("Expected name for identifier".to_string()),
@@ -53,7 +101,144 @@ impl<'a> Parser<'a> {
             LoxErrorsTypes::Syntax("Expect ';' after".to_string()),
         )?;
 
-        Ok(Rc::new(Stmt::Let(LetStmt::new(name, initializer))))
+        Ok(Rc::new(Stmt::Let(LetStmt::new(name, initializer, false))))
+    }
+
+    fn const_declaration(&mut self) -> Result<Rc<Stmt>, LoxResult> {
+        let name = self.consume(
+            TokenType::Identifier,
+            LoxErrorsTypes::Syntax("Expected name for constant after".to_string()),
+        )?;
+        self.consume(
+            TokenType::Assign,
+            LoxErrorsTypes::Syntax("Expected initializer for constant".to_string()),
+        )?;
+        let initializer = self.expression()?;
+        self.consume(
+            TokenType::Semicolon,
+            LoxErrorsTypes::Syntax("Expect ';' after".to_string()),
+        )?;
+
+        Ok(Rc::new(Stmt::Let(LetStmt::new(name, Some(initializer), true))))
+    }
+
+    fn destructure(&mut self) -> Result<Destructure, LoxResult> {
+        let open = self.advance().dup();
+        let is_array = open.token_type == TokenType::LeftBracket;
+        let close = if is_array {
+            TokenType::RightBracket
+        } else {
+            TokenType::RightBrace
+        };
+
+        let mut names: Vec<Token> = Vec::new();
+        let mut rest: Option<Token> = None;
+        while !self.check(close) && !self.is_at_end() {
+            if rest.is_some() {
+                return Err(self.error_handler.error(
+                    self.peek(),
+                    LoxErrorsTypes::Syntax("Rest element must be last, found".to_string()),
+                ));
+            }
+            let is_rest = is_array && self.match_single_token(TokenType::DotDotDot);
+            let name = self.consume(
+                TokenType::Identifier,
+                LoxErrorsTypes::Syntax("Expected identifier in destructuring pattern after".to_string()),
+            )?;
+            if is_rest {
+                rest = Some(name);
+            } else {
+                names.push(name);
+            }
+            if !self.match_single_token(TokenType::Comma) {
+                break;
+            }
+        }
+
+        if is_array {
+            self.consume(
+                close,
+                LoxErrorsTypes::Syntax("Expected ']' after".to_string()),
+            )?;
+            return Ok(Destructure::Array {
+                bracket: open,
+                names,
+                rest,
+            });
+        }
+        self.consume(
+            close,
+            LoxErrorsTypes::Syntax("Expected '}' after".to_string()),
+        )?;
+        Ok(Destructure::Object { brace: open, names })
+    }
+
+    // A destructured parameter is bound to a hidden parameter, named after the
+    // pattern so it can't clash with identifiers, which is unpacked by a
+    // statement added to the start of the body.
+    fn parameter(
+        &mut self,
+        params: &mut Params,
+        prologue: &mut Option<&mut Vec<Rc<Stmt>>>,
+        error: &str,
+    ) -> Result<(), LoxResult> {
+        if params.is_variadic {
+            return Err(self.error_handler.error(
+                self.peek(),
+                LoxErrorsTypes::Syntax("Rest parameter must be last, found".to_string()),
+            ));
+        }
+        if prologue.is_some() && self.match_single_token(TokenType::DotDotDot) {
+            let param = self.consume(
+                TokenType::Identifier,
+                LoxErrorsTypes::Syntax("Expected rest parameter identifier after".to_string()),
+            )?;
+            params.names.push(param);
+            params.is_variadic = true;
+            return Ok(());
+        }
+
+        let param = self.parameter_name(prologue, error)?;
+        if prologue.is_some() && self.match_single_token(TokenType::Assign) {
+            params.defaults.push(self.expression()?);
+        } else if !params.defaults.is_empty() {
+            return Err(self.error_handler.error(
+                &param,
+                LoxErrorsTypes::Syntax(
+                    "Expected a default value for parameter after optional parameters, found"
+                        .to_string(),
+                ),
+            ));
+        }
+        params.names.push(param);
+        Ok(())
+    }
+
+    fn parameter_name(
+        &mut self,
+        prologue: &mut Option<&mut Vec<Rc<Stmt>>>,
+        error: &str,
+    ) -> Result<Token, LoxResult> {
+        if let Some(prologue) = prologue {
+            if self.check(TokenType::LeftBracket) || self.check(TokenType::LeftBrace) {
+                let target = self.destructure()?;
+                let param = Token::new(
+                    TokenType::Identifier,
+                    target.to_string(),
+                    None,
+                    target.token().line,
+                );
+                let value = Rc::new(Expr::Variable(VariableExpr::new(param.dup())));
+                prologue.push(Rc::new(Stmt::Destructure(DestructureStmt::new(
+                    target, value,
+                ))));
+                return Ok(param);
+            }
+        }
+        self.consume(
+            TokenType::Identifier,
+            LoxErrorsTypes::Syntax(error.to_string()),
+        )
     }
 
     fn function(
@@ -72,30 +257,71 @@ impl<'a> Parser<'a> {
             )?
         };
 
+        let mut body: Vec<Rc<Stmt>> = Vec::new();
+        let params = self.parameters_with(Some(&mut body))?;
+
+        self.consume(
+            TokenType::LeftBrace,
+            LoxErrorsTypes::Syntax(format!("Expected '{{' before {kind} body")),
+        )?;
+
+        body.append(&mut self.block_stmt()?);
+
+        Ok(Rc::new(Stmt::Function(FunctionStmt::new(
+            name,
+            Rc::new(params.names),
+            Rc::new(params.defaults),
+            params.is_variadic,
+            Rc::new(body),
+            is_static,
+            is_pub,
+        ))))
+    }
+
+    // Parses a method declaration without a body, `area(scale);`
+    fn signature(&mut self, is_pub: bool) -> Result<Rc<Stmt>, LoxResult> {
+        let name = self.consume(
+            TokenType::Identifier,
+            LoxErrorsTypes::Syntax("Expected method name after".to_string()),
+        )?;
+        let params = self.parameters()?;
+        self.consume(
+            TokenType::Semicolon,
+            LoxErrorsTypes::Syntax("Expected ';' after".to_string()),
+        )?;
+
+        Ok(Rc::new(Stmt::Signature(SignatureStmt::new(
+            name,
+            Rc::new(params),
+            is_pub,
+        ))))
+    }
+
+    fn parameters(&mut self) -> Result<Vec<Token>, LoxResult> {
+        Ok(self.parameters_with(None)?.names)
+    }
+
+    // Destructuring, default values and rest parameters are only allowed when
+    // there is a body to unpack into.
+    fn parameters_with(&mut self, mut prologue: Option<&mut Vec<Rc<Stmt>>>) -> Result<Params, LoxResult> {
         self.consume(
             TokenType::LeftParen,
             LoxErrorsTypes::Syntax("Expected '(' after".to_string()),
         )?;
 
-        let mut params: Vec<Token> = Vec::new();
+        let mut params = Params::default();
 
         if !self.check(TokenType::RightParen) {
-            params.push(self.consume(
-                TokenType::Identifier,
-                LoxErrorsTypes::Syntax("Expected parameter identifier".to_string()),
-            )?);
+            self.parameter(&mut params, &mut prologue, "Expected parameter identifier")?;
 
             while self.match_single_token(TokenType::Comma) {
-                if params.len() >= 255 {
+                if params.names.len() >= 255 {
                     self.error_handler.error(
                         self.peek(),
                         LoxErrorsTypes::Syntax("Can't have more than 255 parameters".to_string()),
                     );
                 }
-                params.push(self.consume(
-                    TokenType::Identifier,
-                    LoxErrorsTypes::Syntax("Expected parameter identifier".to_string()),
-                )?);
+                self.parameter(&mut params, &mut prologue, "Expected parameter identifier")?;
             }
         }
 
@@ -104,25 +330,14 @@ impl<'a> Parser<'a> {
             LoxErrorsTypes::Syntax("Expected ')' after parameters".to_string()),
         )?;
 
-        self.consume(
-            TokenType::LeftBrace,
-            LoxErrorsTypes::Syntax(format!("Expected '{{' before {kind} body")),
-        )?;
-
-        let body = self.block_stmt()?;
-
-        Ok(Rc::new(Stmt::Function(FunctionStmt::new(
-            name,
-            Rc::new(params),
-            Rc::new(body),
-            is_static,
-            is_pub,
-        ))))
+        Ok(params)
     }
 
     fn declaration(&mut self) -> Result<Rc<Stmt>, LoxResult> {
         let result = if self.match_single_token(TokenType::Let) {
             self.var_declaration()
+        } else if self.match_single_token(TokenType::Const) {
+            self.const_declaration()
         } else if self.match_single_token(TokenType::DefFn) {
             self.function(None, "function", false, false)
         } else {
@@ -150,6 +365,14 @@ impl<'a> Parser<'a> {
 
     fn expr_statement(&mut self) -> Result<Rc<Stmt>, LoxResult> {
         let expr = self.expression()?;
+        // A `match` used as a statement reads like a block
+        if matches!(*expr, Expr::Match(_)) && !self.check(TokenType::Semicolon) {
+            return Ok(Rc::new(Stmt::Expression(ExpressionStmt::new(expr))));
+        }
+        // The last expression typed at the prompt can leave out the ';'
+        if self.is_repl && self.is_at_end() {
+            return Ok(Rc::new(Stmt::Expression(ExpressionStmt::new(expr))));
+        }
         self.consume(
             TokenType::Semicolon,
             LoxErrorsTypes::Syntax("Expected ';' after".to_string()),
@@ -287,16 +510,71 @@ impl<'a> Parser<'a> {
         Ok(Rc::new(Stmt::Return(ReturnStmt::new(keyword, value))))
     }
 
+    // Parses `get name() {}` or `set name(value) {}`, `kind` is the
+    // contextual keyword that was matched.
+    fn class_accessor(
+        &mut self,
+        kind: &Token,
+        is_private: bool,
+        is_static: bool,
+    ) -> Result<Rc<Stmt>, LoxResult> {
+        let (what, arity) = if kind.lexeme == "get" {
+            ("getter", 0)
+        } else {
+            ("setter", 1)
+        };
+        let accessor = self.function(None, what, is_static, !is_private)?;
+        if let Stmt::Function(f) = &*accessor {
+            if f.params.len() != arity {
+                return Err(self.error_handler.error(
+                    &f.name,
+                    LoxErrorsTypes::Syntax(format!(
+                        "Expected {what} to take {arity} parameters but got {} for",
+                        f.params.len()
+                    )),
+                ));
+            }
+        }
+        Ok(accessor)
+    }
+
     fn class_field(
         &mut self,
-        methods: &mut Vec<Rc<Stmt>>,
-        fields: &mut Vec<Rc<Stmt>>,
+        members: &mut ClassMembers,
         is_private: bool,
         is_static: bool,
     ) -> Result<(), LoxResult> {
         let prev = self.previous();
+        let is_const = self.match_single_token(TokenType::Const);
+        if self.match_single_token(TokenType::Abstract) {
+            if !members.is_abstract {
+                return Err(self.error_handler.error(
+                    &self.previous(),
+                    LoxErrorsTypes::Syntax(
+                        "Abstract methods are only allowed in abstract classes, found".to_string(),
+                    ),
+                ));
+            }
+            if is_static {
+                return Err(self.error_handler.error(
+                    &self.previous(),
+                    LoxErrorsTypes::Syntax("Cannot make abstract method 'static'".to_string()),
+                ));
+            }
+            members.methods.push(self.signature(!is_private)?);
+            return Ok(());
+        }
         if self.match_single_token(TokenType::Identifier) {
             let name = self.previous();
+            if (name.lexeme == "get" || name.lexeme == "set") && self.check(TokenType::Identifier) {
+                let accessor = self.class_accessor(&name, is_private, is_static)?;
+                if name.lexeme == "get" {
+                    members.getters.push(accessor);
+                } else {
+                    members.setters.push(accessor);
+                }
+                return Ok(());
+            }
             if name.lexeme == "init"
                 && matches!(
                     prev.token_type,
@@ -311,18 +589,25 @@ impl<'a> Parser<'a> {
                     )),
                 ));
             }
+            if is_const && !self.check(TokenType::Assign) {
+                return Err(self.error_handler.error(
+                    &name,
+                    LoxErrorsTypes::Syntax("Expected initializer for constant field".to_string()),
+                ));
+            }
             if self.check(TokenType::LeftParen) {
-                methods.push(self.function(Some(name), "method", is_static, !is_private)?);
+                members.methods.push(self.function(Some(name), "method", is_static, !is_private)?);
                 return Ok(());
             }
 
             if self.match_single_token(TokenType::Assign) {
                 let value = self.expression()?;
-                fields.push(Rc::new(Stmt::Field(FieldStmt::new(
+                members.fields.push(Rc::new(Stmt::Field(FieldStmt::new(
                     name.dup(),
                     !is_private,
                     Some(value),
                     is_static,
+                    is_const,
                 ))));
                 self.consume(
                     TokenType::Semicolon,
@@ -332,11 +617,12 @@ impl<'a> Parser<'a> {
             }
 
             if self.match_single_token(TokenType::Semicolon) {
-                fields.push(Rc::new(Stmt::Field(FieldStmt::new(
+                members.fields.push(Rc::new(Stmt::Field(FieldStmt::new(
                     name.dup(),
                     !is_private,
                     None,
                     is_static,
+                    false,
                 ))));
                 return Ok(());
             }
@@ -359,28 +645,43 @@ impl<'a> Parser<'a> {
         ))
     }
 
-    fn class_statement(&mut self) -> Result<Rc<Stmt>, LoxResult> {
+    fn class_statement(&mut self, is_abstract: bool) -> Result<Rc<Stmt>, LoxResult> {
         let name = self.consume(
             TokenType::Identifier,
             LoxErrorsTypes::Syntax("Expected identifier for class".to_string()),
         )?;
 
+        let mut interfaces: Vec<Token> = Vec::new();
+        if self.match_single_token(TokenType::Implements) {
+            loop {
+                interfaces.push(self.consume(
+                    TokenType::Identifier,
+                    LoxErrorsTypes::Syntax("Expected interface name after".to_string()),
+                )?);
+                if !self.match_single_token(TokenType::Comma) {
+                    break;
+                }
+            }
+        }
+
         self.consume(
             TokenType::LeftBrace,
             LoxErrorsTypes::Syntax("Expected '{' before class body".to_string()),
         )?;
 
-        let mut fields: Vec<Rc<Stmt>> = Vec::new();
-        let mut methods: Vec<Rc<Stmt>> = Vec::new();
+        let mut members = ClassMembers {
+            is_abstract,
+            ..Default::default()
+        };
         while !self.check(TokenType::RightBrace) && !self.is_at_end() {
             if self.match_single_token(TokenType::Public) {
-                self.class_field(&mut methods, &mut fields, false, false)?;
+                self.class_field(&mut members, false, false)?;
             } else if self.match_single_token(TokenType::Private) {
-                self.class_field(&mut methods, &mut fields, true, false)?;
+                self.class_field(&mut members, true, false)?;
             } else if self.match_single_token(TokenType::Static) {
-                self.class_field(&mut methods, &mut fields, false, true)?;
+                self.class_field(&mut members, false, true)?;
             } else {
-                self.class_field(&mut methods, &mut fields, true, false)?;
+                self.class_field(&mut members, true, false)?;
             }
         }
 
@@ -389,7 +690,76 @@ impl<'a> Parser<'a> {
             LoxErrorsTypes::Syntax("Expected '}' after class body".to_string()),
         )?;
 
-        Ok(Rc::new(Stmt::Class(ClassStmt::new(name, fields, methods))))
+        Ok(Rc::new(Stmt::Class(ClassStmt::new(
+            name,
+            members.fields,
+            members.methods,
+            members.getters,
+            members.setters,
+            is_abstract,
+            interfaces,
+        ))))
+    }
+
+    fn interface_statement(&mut self) -> Result<Rc<Stmt>, LoxResult> {
+        let name = self.consume(
+            TokenType::Identifier,
+            LoxErrorsTypes::Syntax("Expected identifier for interface".to_string()),
+        )?;
+
+        self.consume(
+            TokenType::LeftBrace,
+            LoxErrorsTypes::Syntax("Expected '{' before interface body".to_string()),
+        )?;
+
+        let mut methods: Vec<Rc<Stmt>> = Vec::new();
+        while !self.check(TokenType::RightBrace) && !self.is_at_end() {
+            methods.push(self.signature(true)?);
+        }
+
+        self.consume(
+            TokenType::RightBrace,
+            LoxErrorsTypes::Syntax("Expected '}' after interface body".to_string()),
+        )?;
+
+        Ok(Rc::new(Stmt::Interface(InterfaceStmt::new(name, methods))))
+    }
+
+    fn enum_statement(&mut self) -> Result<Rc<Stmt>, LoxResult> {
+        let name = self.consume(
+            TokenType::Identifier,
+            LoxErrorsTypes::Syntax("Expected identifier for enum".to_string()),
+        )?;
+
+        self.consume(
+            TokenType::LeftBrace,
+            LoxErrorsTypes::Syntax("Expected '{' before enum body".to_string()),
+        )?;
+
+        let mut variants: Vec<Rc<Stmt>> = Vec::new();
+        while !self.check(TokenType::RightBrace) && !self.is_at_end() {
+            let variant = self.consume(
+                TokenType::Identifier,
+                LoxErrorsTypes::Syntax("Expected variant name after".to_string()),
+            )?;
+            let fields = if self.check(TokenType::LeftParen) {
+                Some(Rc::new(self.parameters()?))
+            } else {
+                None
+            };
+            variants.push(Rc::new(Stmt::Variant(VariantStmt::new(variant, fields))));
+
+            if !self.match_single_token(TokenType::Comma) {
+                break;
+            }
+        }
+
+        self.consume(
+            TokenType::RightBrace,
+            LoxErrorsTypes::Syntax("Expected '}' after enum body".to_string()),
+        )?;
+
+        Ok(Rc::new(Stmt::Enum(EnumStmt::new(name, variants))))
     }
 
     fn statement(&mut self) -> Result<Rc<Stmt>, LoxResult> {
@@ -422,7 +792,23 @@ impl<'a> Parser<'a> {
         }
 
         if self.match_single_token(TokenType::Class) {
-            return self.class_statement();
+            return self.class_statement(false);
+        }
+
+        if self.match_single_token(TokenType::Abstract) {
+            self.consume(
+                TokenType::Class,
+                LoxErrorsTypes::Syntax("Expected 'class' after".to_string()),
+            )?;
+            return self.class_statement(true);
+        }
+
+        if self.match_single_token(TokenType::Interface) {
+            return self.interface_statement();
+        }
+
+        if self.match_single_token(TokenType::Enum) {
+            return self.enum_statement();
         }
 
         self.expr_statement()
@@ -442,24 +828,20 @@ impl<'a> Parser<'a> {
             LoxErrorsTypes::Syntax("Expected '(' after function declaration".to_string()),
         )?;
 
-        let mut params: Vec<Token> = Vec::new();
+        let mut params = Params::default();
+        let mut body: Vec<Rc<Stmt>> = Vec::new();
+        let mut prologue = Some(&mut body);
 
         if !self.check(TokenType::RightParen) {
-            params.push(self.consume(
-                TokenType::Identifier,
-                LoxErrorsTypes::Syntax("Expected identifier got".to_string()),
-            )?);
+            self.parameter(&mut params, &mut prologue, "Expected identifier got")?;
             while self.match_single_token(TokenType::Comma) {
-                if params.len() >= 255 {
+                if params.names.len() >= 255 {
                     self.error_handler.error(
                         self.peek(),
                         LoxErrorsTypes::Syntax("Can't have more than 255 parameters".to_string()),
                     );
                 }
-                params.push(self.consume(
-                    TokenType::Identifier,
-                    LoxErrorsTypes::Syntax("Expected identifier got".to_string()),
-                )?);
+                self.parameter(&mut params, &mut prologue, "Expected identifier got")?;
             }
         }
 
@@ -468,26 +850,44 @@ impl<'a> Parser<'a> {
             LoxErrorsTypes::Syntax("Expected ')' after parameters".to_string()),
         )?;
 
-        self.consume(
-            TokenType::LeftBrace,
-            LoxErrorsTypes::Syntax("Expected '{' before function body".to_string()),
-        )?;
+        // `lm(a) => a + 1` is sugar for `lm(a) { return a + 1; }`
+        if self.match_single_token(TokenType::Arrow) {
+            let arrow = self.previous().clone();
+            let value = self.expression()?;
+            body.push(Rc::new(Stmt::Return(ReturnStmt::new(arrow, value))));
+        } else {
+            self.consume(
+                TokenType::LeftBrace,
+                LoxErrorsTypes::Syntax("Expected '{' or '=>' before function body".to_string()),
+            )?;
 
-        let body = self.block_stmt()?;
+            body.append(&mut self.block_stmt()?);
+        }
 
         Ok(Rc::new(Expr::Lambda(LambdaExpr::new(
-            Rc::new(params),
+            Rc::new(params.names),
+            Rc::new(params.defaults),
+            params.is_variadic,
             Rc::new(body),
         ))))
     }
 
+    // Array literal elements and call arguments can be spread, `...arr`
+    fn element(&mut self) -> Result<Rc<Expr>, LoxResult> {
+        if self.match_single_token(TokenType::DotDotDot) {
+            let token = self.previous();
+            return Ok(Rc::new(Expr::Spread(SpreadExpr::new(token, self.expression()?))));
+        }
+        self.expression()
+    }
+
     fn array_expr(&mut self) -> Result<Rc<Expr>, LoxResult> {
         let mut elems = Vec::new();
 
         if !self.check(TokenType::RightBracket) {
-            elems.push(self.expression()?);
+            elems.push(self.element()?);
             while self.match_single_token(TokenType::Comma) {
-                elems.push(self.expression()?);
+                elems.push(self.element()?);
             }
         }
 
@@ -558,6 +958,10 @@ impl<'a> Parser<'a> {
             return self.array_expr();
         }
 
+        if self.match_single_token(TokenType::Match) {
+            return self.match_expr();
+        }
+
         if self.curr == 0 {
             return Err(self.error_handler.error(
                 self.peek(),
@@ -572,6 +976,178 @@ impl<'a> Parser<'a> {
         ))
     }
 
+    fn match_expr(&mut self) -> Result<Rc<Expr>, LoxResult> {
+        let keyword = self.previous();
+        self.consume(
+            TokenType::LeftParen,
+            LoxErrorsTypes::Syntax("Expected '(' after".to_string()),
+        )?;
+        let value = self.expression()?;
+        self.consume(
+            TokenType::RightParen,
+            LoxErrorsTypes::Syntax("Expected ')' after expression, at".to_string()),
+        )?;
+        self.consume(
+            TokenType::LeftBrace,
+            LoxErrorsTypes::Syntax("Expected '{' before match arms".to_string()),
+        )?;
+
+        let mut arms: Vec<Rc<MatchArm>> = Vec::new();
+        while !self.check(TokenType::RightBrace) && !self.is_at_end() {
+            let pattern = self.pattern()?;
+            let guard = if self.match_single_token(TokenType::If) {
+                Some(self.expression()?)
+            } else {
+                None
+            };
+            let arrow = self.consume(
+                TokenType::Arrow,
+                LoxErrorsTypes::Syntax("Expected '=>' after pattern".to_string()),
+            )?;
+            let body = if self.match_single_token(TokenType::LeftBrace) {
+                Rc::new(Stmt::Block(BlockStmt::new(self.block_stmt()?)))
+            } else {
+                Rc::new(Stmt::Expression(ExpressionStmt::new(self.expression()?)))
+            };
+            let is_block = matches!(*body, Stmt::Block(_));
+            arms.push(Rc::new(MatchArm {
+                pattern,
+                guard,
+                arrow,
+                body,
+            }));
+
+            // Arms with a block body don't need a comma
+            if !self.match_single_token(TokenType::Comma) && !is_block {
+                break;
+            }
+        }
+
+        self.consume(
+            TokenType::RightBrace,
+            LoxErrorsTypes::Syntax("Expected '}' after match arms".to_string()),
+        )?;
+
+        if arms.is_empty() {
+            return Err(self.error_handler.error(
+                &keyword,
+                LoxErrorsTypes::Syntax("Expected at least one arm for".to_string()),
+            ));
+        }
+
+        Ok(Rc::new(Expr::Match(MatchExpr::new(keyword, value, arms))))
+    }
+
+    fn pattern(&mut self) -> Result<Pattern, LoxResult> {
+        let mut patterns = vec![self.single_pattern()?];
+        while self.match_single_token(TokenType::Pipe) {
+            patterns.push(self.single_pattern()?);
+        }
+
+        if patterns.len() == 1 {
+            return Ok(patterns.remove(0));
+        }
+        Ok(Pattern::Or(patterns))
+    }
+
+    fn pattern_list(&mut self, close: TokenType, error: &str) -> Result<Vec<Pattern>, LoxResult> {
+        let mut patterns: Vec<Pattern> = Vec::new();
+        if !self.check(close) {
+            loop {
+                patterns.push(self.pattern()?);
+                if !self.match_single_token(TokenType::Comma) {
+                    break;
+                }
+            }
+        }
+        self.consume(close, LoxErrorsTypes::Syntax(error.to_string()))?;
+        Ok(patterns)
+    }
+
+    fn single_pattern(&mut self) -> Result<Pattern, LoxResult> {
+        if self.match_single_token(TokenType::LeftBracket) {
+            let bracket = self.previous();
+            let patterns = self.pattern_list(TokenType::RightBracket, "Expected ']' after")?;
+            return Ok(Pattern::Array(bracket, patterns));
+        }
+
+        if self.match_single_token(TokenType::Identifier) {
+            let name = self.previous();
+            if self.match_single_token(TokenType::Dot) {
+                let variant = self.consume(
+                    TokenType::Identifier,
+                    LoxErrorsTypes::Syntax("Expected variant name after".to_string()),
+                )?;
+                let fields = if self.match_single_token(TokenType::LeftParen) {
+                    Some(self.pattern_list(TokenType::RightParen, "Expected ')' after")?)
+                } else {
+                    None
+                };
+                return Ok(Pattern::Variant {
+                    enum_expr: Rc::new(Expr::Variable(VariableExpr::new(name))),
+                    name: variant,
+                    fields,
+                });
+            }
+            if name.lexeme == "_" {
+                return Ok(Pattern::Wildcard(name));
+            }
+            return Ok(Pattern::Binding(name));
+        }
+
+        let (token, start) = self.pattern_literal()?;
+        if self.is_match(vec![TokenType::DotDot, TokenType::DotDotEqual]) {
+            let inclusive = self.previous().token_type == TokenType::DotDotEqual;
+            let (_, end) = self.pattern_literal()?;
+            if !matches!((&start, &end), (Literal::Number(_), Literal::Number(_))) {
+                return Err(self.error_handler.error(
+                    &token,
+                    LoxErrorsTypes::Syntax("Expected numbers as range bounds for".to_string()),
+                ));
+            }
+            return Ok(Pattern::Range {
+                start,
+                end,
+                inclusive,
+            });
+        }
+        Ok(Pattern::Literal(token, start))
+    }
+
+    fn pattern_literal(&mut self) -> Result<(Token, Literal), LoxResult> {
+        if self.is_match(vec![TokenType::True, TokenType::False, TokenType::None]) {
+            let token = self.previous();
+            let value = match token.token_type {
+                TokenType::True => Literal::Bool(true),
+                TokenType::False => Literal::Bool(false),
+                _ => Literal::None,
+            };
+            return Ok((token, value));
+        }
+
+        let negate = self.match_single_token(TokenType::Minus);
+        if self.is_match(vec![TokenType::Number, TokenType::String]) {
+            let token = self.previous();
+            match token.literal.as_ref() {
+                Some(Literal::Number(num)) => {
+                    let num = *num.num.borrow();
+                    let num = if negate { -num } else { num };
+                    return Ok((token, Literal::Number(Rc::new(LoxNumber::new(num)))));
+                }
+                Some(Literal::Str(str)) if !negate => {
+                    let str = LoxString::new(str.string.borrow().to_string());
+                    return Ok((token, Literal::Str(Rc::new(str))));
+                }
+                _ => {}
+            }
+        }
+
+        Err(self.error_handler.error(
+            &self.previous(),
+            LoxErrorsTypes::Syntax("Expected pattern after".to_string()),
+        ))
+    }
+
     fn postfix_operation(&mut self) -> Result<Rc<Expr>, LoxResult> {
         let expr = self.primary()?;
         if self.is_match(vec![TokenType::PlusPlus, TokenType::MinusMinus]) {
@@ -588,6 +1164,7 @@ impl<'a> Parser<'a> {
 
     fn finish_call(&mut self, callee: Rc<Expr>) -> Result<Rc<Expr>, LoxResult> {
         let mut args: Vec<Rc<Expr>> = Vec::new();
+        let mut keywords: Vec<Token> = Vec::new();
         if self.check(TokenType::Semicolon) {
             return Err(self.error_handler.error(
                 self.peek(),
@@ -601,9 +1178,27 @@ impl<'a> Parser<'a> {
                     LoxErrorsTypes::Parse("Cannot have more than 255 arguments".to_string()),
                 );
             }
-            args.push(self.expression()?);
-            while self.match_single_token(TokenType::Comma) {
-                args.push(self.expression()?);
+            loop {
+                // Keyword arguments, `greeting: "Hi"`, come after the positional ones
+                if self.check(TokenType::Identifier) && self.check_next(TokenType::Colon) {
+                    keywords.push(self.advance().dup());
+                    self.advance();
+                } else if !keywords.is_empty() {
+                    return Err(self.error_handler.error(
+                        self.peek(),
+                        LoxErrorsTypes::Syntax(
+                            "Expected keyword argument after keyword arguments, found".to_string(),
+                        ),
+                    ));
+                }
+                args.push(if keywords.is_empty() {
+                    self.element()?
+                } else {
+                    self.expression()?
+                });
+                if !self.match_single_token(TokenType::Comma) {
+                    break;
+                }
             }
         }
 
@@ -611,7 +1206,7 @@ impl<'a> Parser<'a> {
             TokenType::RightParen,
             LoxErrorsTypes::Syntax("Expected ')' after".to_string()),
         )?;
-        Ok(Rc::new(Expr::Call(CallExpr::new(callee, paren, args))))
+        Ok(Rc::new(Expr::Call(CallExpr::new(callee, paren, args, keywords))))
     }
 
     fn call(&mut self) -> Result<Rc<Expr>, LoxResult> {
@@ -626,6 +1221,18 @@ impl<'a> Parser<'a> {
                     LoxErrorsTypes::Syntax("Expected property name after".to_string()),
                 )?;
                 expr = Rc::new(Expr::Get(GetExpr::new(expr, name)))
+            } else if self.check(TokenType::QuestionDot) && !self.check_next(TokenType::LeftBracket) {
+                let token = self.advance().dup();
+                let object = Rc::new(Expr::Optional(OptionalExpr::new(token, expr)));
+                if self.match_single_token(TokenType::LeftParen) {
+                    expr = self.finish_call(object)?;
+                } else {
+                    let name = self.consume(
+                        TokenType::Identifier,
+                        LoxErrorsTypes::Syntax("Expected property name after".to_string()),
+                    )?;
+                    expr = Rc::new(Expr::Get(GetExpr::new(object, name)))
+                }
             } else {
                 break;
             }
@@ -636,7 +1243,15 @@ impl<'a> Parser<'a> {
 
     fn finish_index(&mut self, var: Rc<Expr>) -> Result<Rc<Expr>, LoxResult> {
         let bracket = self.previous();
-        let index = self.expression()?;
+        let start = if self.check(TokenType::Colon) {
+            None
+        } else {
+            Some(self.expression()?)
+        };
+        let index = match start {
+            Some(index) if !self.check(TokenType::Colon) => index,
+            start => self.slice(bracket.dup(), start)?,
+        };
         self.consume(
             TokenType::RightBracket,
             LoxErrorsTypes::Syntax("Expected ']' after".to_string()),
@@ -644,20 +1259,55 @@ impl<'a> Parser<'a> {
         Ok(Rc::new(Expr::Index(IndexExpr::new(var, bracket, index))))
     }
 
+    // Parses the rest of `[start:end:step]` after `start`, every part is optional
+    fn slice(&mut self, bracket: Token, start: Option<Rc<Expr>>) -> Result<Rc<Expr>, LoxResult> {
+        self.consume(
+            TokenType::Colon,
+            LoxErrorsTypes::Syntax("Expected ':' in slice".to_string()),
+        )?;
+        let mut end = None;
+        if !self.check(TokenType::Colon) && !self.check(TokenType::RightBracket) {
+            end = Some(self.expression()?);
+        }
+        let mut step = None;
+        if self.match_single_token(TokenType::Colon) && !self.check(TokenType::RightBracket) {
+            step = Some(self.expression()?);
+        }
+        Ok(Rc::new(Expr::Slice(SliceExpr::new(bracket, start, end, step))))
+    }
+
     fn array_subscript(&mut self) -> Result<Rc<Expr>, LoxResult> {
         let mut expr = self.call()?;
 
         loop {
             if self.match_single_token(TokenType::LeftBracket) {
                 expr = self.finish_index(expr)?;
+            } else if self.check(TokenType::QuestionDot) && self.check_next(TokenType::LeftBracket) {
+                let token = self.advance().dup();
+                self.advance();
+                expr = self.finish_index(Rc::new(Expr::Optional(OptionalExpr::new(token, expr))))?;
             } else {
                 break;
             }
         }
 
+        // The whole chain evaluates to none when an optional link short-circuits
+        if Parser::is_optional_chain(&expr) {
+            return Ok(Rc::new(Expr::Chain(ChainExpr::new(expr))));
+        }
         Ok(expr)
     }
 
+    fn is_optional_chain(expr: &Expr) -> bool {
+        match expr {
+            Expr::Optional(_) => true,
+            Expr::Get(get) => Parser::is_optional_chain(&get.object),
+            Expr::Call(call) => Parser::is_optional_chain(&call.callee),
+            Expr::Index(index) => Parser::is_optional_chain(&index.identifier),
+            _ => false,
+        }
+    }
+
     fn prefix_operation(&mut self) -> Result<Rc<Expr>, LoxResult> {
         if self.is_match(vec![TokenType::PlusPlus, TokenType::MinusMinus]) {
             let op = self.previous();
@@ -746,6 +1396,18 @@ impl<'a> Parser<'a> {
         Ok(expr)
     }
 
+    // `a ?? b` binds looser than `or`, `b` is only evaluated when `a` is none
+    fn coalesce(&mut self) -> Result<Rc<Expr>, LoxResult> {
+        let mut expr = self.or()?;
+
+        while self.match_single_token(TokenType::QuestionQuestion) {
+            let op = self.previous();
+            let right = self.or()?;
+            expr = Rc::new(Expr::Logical(LogicalExpr::new(expr, op, right)));
+        }
+        Ok(expr)
+    }
+
     fn or(&mut self) -> Result<Rc<Expr>, LoxResult> {
         let mut expr = self.and()?;
 
@@ -758,7 +1420,7 @@ impl<'a> Parser<'a> {
     }
 
     fn ternary(&mut self) -> Result<Rc<Expr>, LoxResult> {
-        let expr = self.or()?;
+        let expr = self.coalesce()?;
 
         if self.match_single_token(TokenType::QuestionMark) {
             let operator = self.tokens.get(self.curr - 1).unwrap().dup();
@@ -782,48 +1444,62 @@ impl<'a> Parser<'a> {
         Ok(expr)
     }
 
+    fn assignment_target(
+        &mut self,
+        expr: Rc<Expr>,
+        token: Token,
+        value: Rc<Expr>,
+    ) -> Result<Rc<Expr>, LoxResult> {
+        match &*expr {
+            Expr::Variable(var) => {
+                let name = var.name.dup();
+                Ok(Rc::new(Expr::Assign(AssignExpr::new(name, value))))
+            }
+            Expr::Get(prop) => Ok(Rc::new(Expr::Set(SetExpr::new(
+                prop.object.clone(),
+                prop.name.dup(),
+                value,
+                token,
+            )))),
+            Expr::Index(ind) => {
+                if self.current_token.is_none() {
+                    return Err(self.error_handler.error(
+                        &token,
+                        LoxErrorsTypes::Syntax("Unexpected token".to_string()),
+                    ));
+                }
+                Ok(Rc::new(Expr::UpdateIndex(UpdateIndexExpr::new(
+                    self.current_token.as_ref().unwrap().dup(),
+                    ind.identifier.clone(),
+                    ind.bracket.dup(),
+                    ind.index.clone(),
+                    value,
+                ))))
+            }
+            _ => Err(self.error_handler.error(
+                &token,
+                LoxErrorsTypes::Parse("Invalid assignment target".to_string()),
+            )),
+        }
+    }
+
     fn assignment(&mut self) -> Result<Rc<Expr>, LoxResult> {
         let expr = self.ternary()?;
 
         if self.match_single_token(TokenType::Assign) {
             let token = self.previous();
             let value = self.assignment()?;
+            return self.assignment_target(expr, token, value);
+        }
 
-            match &*expr {
-                Expr::Variable(var) => {
-                    let name = var.name.dup();
-                    return Ok(Rc::new(Expr::Assign(AssignExpr::new(name, value))));
-                }
-                Expr::Get(prop) => {
-                    return Ok(Rc::new(Expr::Set(SetExpr::new(
-                        prop.object.clone(),
-                        prop.name.dup(),
-                        value,
-                        token,
-                    ))));
-                }
-                Expr::Index(ind) => {
-                    if self.current_token.is_none() {
-                        return Err(self.error_handler.error(
-                            &token,
-                            LoxErrorsTypes::Syntax("Unexpected token".to_string()),
-                        ));
-                    }
-                    return Ok(Rc::new(Expr::UpdateIndex(UpdateIndexExpr::new(
-                        self.current_token.as_ref().unwrap().dup(),
-                        ind.identifier.clone(),
-                        ind.bracket.dup(),
-                        ind.index.clone(),
-                        value,
-                    ))));
-                }
-                _ => {
-                    return Err(self.error_handler.error(
-                        &token,
-                        LoxErrorsTypes::Parse("Invalid assignment target".to_string()),
-                    ));
-                }
-            }
+        // `a ??= b` is `a ?? (a = b)`, `a` is only assigned when it is none
+        if self.match_single_token(TokenType::QuestionQuestionEqual) {
+            let token = self.previous();
+            let value = self.assignment()?;
+            let assign_token = Token::new(TokenType::Assign, "=".to_string(), None, token.line);
+            let assign = self.assignment_target(expr.clone(), assign_token, value)?;
+            let op = Token::new(TokenType::QuestionQuestion, "??".to_string(), None, token.line);
+            return Ok(Rc::new(Expr::Logical(LogicalExpr::new(expr, op, assign))));
         }
 
         Ok(expr)
@@ -840,7 +1516,7 @@ impl<'a> Parser<'a> {
             TokenType::ModEqual
         ]) {
             let token = self.previous();
-            let value = self.primary()?;
+            let value = self.assignment()?;
 
             match self.peek().token_type {
                 TokenType::SlashEqual
@@ -914,6 +1590,13 @@ impl<'a> Parser<'a> {
         self.peek().token_type == token
     }
 
+    fn check_next(&self, token: TokenType) -> bool {
+        match self.tokens.get(self.curr + 1) {
+            Some(next) => next.token_type == token,
+            None => false,
+        }
+    }
+
     fn match_single_token(&mut self, token: TokenType) -> bool {
         if self.check(token) {
             self.advance();
@@ -941,8 +1624,12 @@ impl<'a> Parser<'a> {
             }
             match self.peek().token_type {
                 TokenType::Class
+                | TokenType::Abstract
+                | TokenType::Interface
+                | TokenType::Enum
                 | TokenType::DefFn
                 | TokenType::Let
+                | TokenType::Const
                 | TokenType::For
                 | TokenType::If
                 | TokenType::Else
@@ -991,6 +1678,28 @@ mod tests {
         fn execute(&self, stmt: Rc<Stmt>) -> Result<String, LoxResult> {
             stmt.accept(stmt.clone(), self, 0_u16)
         }
+
+        fn params(
+            &self,
+            params: &[Token],
+            defaults: &[Rc<Expr>],
+            is_variadic: bool,
+        ) -> Result<String, LoxResult> {
+            let fixed = params.len() - usize::from(is_variadic);
+            let required = fixed - defaults.len();
+            let mut strs: Vec<String> = Vec::new();
+            for (i, param) in params.iter().enumerate() {
+                if i == fixed {
+                    strs.push(format!("...{}", param.lexeme));
+                } else if i >= required {
+                    let default = self.evaluate(defaults[i - required].clone())?;
+                    strs.push(format!("{} = {default}", param.lexeme));
+                } else {
+                    strs.push(param.lexeme.to_string());
+                }
+            }
+            Ok(strs.join(", "))
+        }
     }
 
     impl<'a> VisitorExpr<String> for AstTraverser<'a> {
@@ -1005,7 +1714,11 @@ mod tests {
             if expr.args.len() > 0 {
                 str.push(' ');
             }
+            let positional = expr.args.len() - expr.keywords.len();
             for (i, arg) in expr.args.iter().enumerate() {
+                if i >= positional {
+                    str.push_str(&format!("{}: ", expr.keywords[i - positional].lexeme));
+                }
                 str = str.add(&self.evaluate(arg.clone())?);
                 if expr.args.len() > 1 && expr.args.len() - 1 != i {
                     str.push(' ');
@@ -1055,13 +1768,7 @@ mod tests {
             expr: &LambdaExpr,
             _: u16,
         ) -> Result<String, LoxResult> {
-            let mut params = "".to_string();
-            for (i, param) in expr.params.iter().enumerate() {
-                params.push_str(&param.lexeme);
-                if expr.params.len() > 1 && expr.params.len() - 1 != i {
-                    params.push_str(", ");
-                }
-            }
+            let params = self.params(&expr.params, &expr.defaults, expr.is_variadic)?;
             let mut body = "{ ".to_string();
 
             for stmt in expr.body.iter() {
@@ -1147,6 +1854,55 @@ mod tests {
             Ok(str)
         }
 
+        fn visit_optional_expr(
+            &self,
+            _: Rc<Expr>,
+            expr: &OptionalExpr,
+            _: u16,
+        ) -> Result<String, LoxResult> {
+            Ok(format!("OptionalExpr {}", self.evaluate(expr.object.clone())?))
+        }
+
+        fn visit_chain_expr(
+            &self,
+            _: Rc<Expr>,
+            expr: &ChainExpr,
+            _: u16,
+        ) -> Result<String, LoxResult> {
+            Ok(format!("ChainExpr {}", self.evaluate(expr.expr.clone())?))
+        }
+
+        fn visit_spread_expr(
+            &self,
+            _: Rc<Expr>,
+            expr: &SpreadExpr,
+            _: u16,
+        ) -> Result<String, LoxResult> {
+            Ok(format!("SpreadExpr ...{}", self.evaluate(expr.expr.clone())?))
+        }
+
+        fn visit_match_expr(
+            &self,
+            _: Rc<Expr>,
+            expr: &MatchExpr,
+            _: u16,
+        ) -> Result<String, LoxResult> {
+            let mut arms: Vec<String> = Vec::new();
+            for arm in expr.arms.iter() {
+                let guard = match &arm.guard {
+                    Some(guard) => format!(" if {}", self.evaluate(guard.clone())?),
+                    None => String::new(),
+                };
+                let body = self.execute(arm.body.clone())?;
+                arms.push(format!("{}{guard} => {body}", arm.pattern));
+            }
+            Ok(format!(
+                "MatchExpr {} {{ {} }}",
+                self.evaluate(expr.value.clone())?,
+                arms.join(", ")
+            ))
+        }
+
         fn visit_array_expr(
             &self,
             _: Rc<Expr>,
@@ -1166,6 +1922,22 @@ mod tests {
             Ok(str)
         }
 
+        fn visit_slice_expr(
+            &self,
+            _: Rc<Expr>,
+            expr: &SliceExpr,
+            _: u16,
+        ) -> Result<String, LoxResult> {
+            let mut parts = Vec::new();
+            for part in [&expr.start, &expr.end, &expr.step] {
+                match part {
+                    Some(e) => parts.push(self.evaluate(e.clone())?),
+                    None => parts.push("".to_string()),
+                }
+            }
+            Ok(format!("SliceExpr {}", parts.join(":")))
+        }
+
         fn visit_index_expr(
             &self,
             _: Rc<Expr>,
@@ -1235,7 +2007,8 @@ mod tests {
             if stmt.initializer.is_some() {
                 initializer = self.evaluate(stmt.initializer.as_ref().unwrap().clone())?;
             }
-            let str = format!("LetStmt {} = {}", stmt.name.lexeme, initializer);
+            let keyword = if stmt.is_const { "const " } else { "" };
+            let str = format!("LetStmt {keyword}{} = {}", stmt.name.lexeme, initializer);
             Ok(str)
         }
 
@@ -1334,13 +2107,7 @@ mod tests {
             stmt: &FunctionStmt,
             _: u16,
         ) -> Result<String, LoxResult> {
-            let mut params = "".to_string();
-            for (i, param) in stmt.params.iter().enumerate() {
-                params.push_str(&param.lexeme);
-                if stmt.params.len() - 1 != i {
-                    params.push_str(", ");
-                }
-            }
+            let params = self.params(&stmt.params, &stmt.defaults, stmt.is_variadic)?;
             let mut body = "{ ".to_string();
 
             for (i, val) in stmt.body.iter().enumerate() {
@@ -1361,6 +2128,16 @@ mod tests {
             Ok(str)
         }
 
+        fn visit_destructure_stmt(
+            &self,
+            _: Rc<Stmt>,
+            stmt: &DestructureStmt,
+            _: u16,
+        ) -> Result<String, LoxResult> {
+            let init = self.evaluate(stmt.initializer.clone())?;
+            Ok(format!("DestructureStmt {} = {init}", stmt.target))
+        }
+
         fn visit_expression_stmt(
             &self,
             _: Rc<Stmt>,
@@ -1372,17 +2149,83 @@ mod tests {
             Ok(str)
         }
 
-        fn visit_class_stmt(
+        fn visit_class_stmt(
+            &self,
+            _: Rc<Stmt>,
+            stmt: &ClassStmt,
+            _: u16,
+        ) -> Result<String, LoxResult> {
+            let mut methods = "".to_string();
+            for s in stmt.methods.iter() {
+                methods.push_str(self.execute(s.clone())?.as_str());
+            }
+            for s in stmt.getters.iter() {
+                methods.push_str(format!("get {}", self.execute(s.clone())?).as_str());
+            }
+            for s in stmt.setters.iter() {
+                methods.push_str(format!("set {}", self.execute(s.clone())?).as_str());
+            }
+            let mut name = stmt.name.lexeme.to_string();
+            if stmt.is_abstract {
+                name = format!("abstract {name}");
+            }
+            if !stmt.interfaces.is_empty() {
+                let interfaces: Vec<&str> =
+                    stmt.interfaces.iter().map(|i| i.lexeme.as_str()).collect();
+                name = format!("{name} implements {}", interfaces.join(", "));
+            }
+            Ok(format!("ClassStmt {} {{ {} }}", name, methods))
+        }
+
+        fn visit_signature_stmt(
+            &self,
+            _: Rc<Stmt>,
+            stmt: &SignatureStmt,
+            _: u16,
+        ) -> Result<String, LoxResult> {
+            let params: Vec<&str> = stmt.params.iter().map(|p| p.lexeme.as_str()).collect();
+            Ok(format!("SignatureStmt {}({})", stmt.name.lexeme, params.join(", ")))
+        }
+
+        fn visit_enum_stmt(
+            &self,
+            _: Rc<Stmt>,
+            stmt: &EnumStmt,
+            _: u16,
+        ) -> Result<String, LoxResult> {
+            let mut variants: Vec<String> = Vec::new();
+            for s in stmt.variants.iter() {
+                variants.push(self.execute(s.clone())?);
+            }
+            Ok(format!("EnumStmt {} {{ {} }}", stmt.name.lexeme, variants.join(" ")))
+        }
+
+        fn visit_variant_stmt(
+            &self,
+            _: Rc<Stmt>,
+            stmt: &VariantStmt,
+            _: u16,
+        ) -> Result<String, LoxResult> {
+            match &stmt.fields {
+                Some(fields) => {
+                    let fields: Vec<&str> = fields.iter().map(|f| f.lexeme.as_str()).collect();
+                    Ok(format!("VariantStmt {}({})", stmt.name.lexeme, fields.join(", ")))
+                }
+                None => Ok(format!("VariantStmt {}", stmt.name.lexeme)),
+            }
+        }
+
+        fn visit_interface_stmt(
             &self,
             _: Rc<Stmt>,
-            stmt: &ClassStmt,
+            stmt: &InterfaceStmt,
             _: u16,
         ) -> Result<String, LoxResult> {
-            let mut methods = "".to_string();
+            let mut methods: Vec<String> = Vec::new();
             for s in stmt.methods.iter() {
-                methods.push_str(self.execute(s.clone())?.as_str());
+                methods.push(self.execute(s.clone())?);
             }
-            Ok(format!("ClassStmt {} {{ {} }}", stmt.name.lexeme, methods))
+            Ok(format!("InterfaceStmt {} {{ {} }}", stmt.name.lexeme, methods.join(" ")))
         }
 
         fn visit_field_stmt(
@@ -1391,11 +2234,12 @@ mod tests {
             stmt: &FieldStmt,
             _: u16,
         ) -> Result<String, LoxResult> {
+            let keyword = if stmt.is_const { "const " } else { "" };
             if stmt.is_pub {
-                return Ok(format!("FieldStmt public {}", stmt.name.lexeme));
+                return Ok(format!("FieldStmt public {keyword}{}", stmt.name.lexeme));
             }
 
-            Ok(format!("FieldStmt private {}", stmt.name.lexeme))
+            Ok(format!("FieldStmt private {keyword}{}", stmt.name.lexeme))
         }
     }
 
@@ -1422,6 +2266,21 @@ mod tests {
         }
     }
 
+    fn perform_repl(src: &str, expected: Vec<&str>) {
+        let e_handler = LoxErrorHandler::new();
+        let mut scanner = Scanner::new(src, &e_handler);
+        let tokens = scanner.scan_tokens().expect("failed to scan");
+        let mut parser = Parser::new(tokens);
+        parser.set_is_repl(true);
+        let ast = parser.parse().unwrap_or_else(|_| panic!("failed {src}"));
+        let mut tr = AstTraverser::new(&ast);
+        let strings = tr.gen().unwrap_or_else(|_| panic!("failed {src}"));
+        assert_eq!(strings.len(), expected.len());
+        for (a, b) in strings.iter().zip(expected.iter()) {
+            assert_eq!(a, b);
+        }
+    }
+
     fn perform_err(src: &str, expected: LoxErrorsTypes) {
         let e_handler = LoxErrorHandler::new();
         let mut scanner = Scanner::new(src, &e_handler);
@@ -1447,6 +2306,24 @@ mod tests {
         perform(src, expected);
     }
 
+    #[test]
+    fn repl_trailing_expression() {
+        let expected =
+            vec!["ExpressionStmt BinaryExpr LiteralExpr Number { 1 } + LiteralExpr Number { 2 }"];
+        perform_repl("1 + 2\n", expected);
+        let expected = vec![
+            "ExpressionStmt BinaryExpr LiteralExpr Number { 1 } + LiteralExpr Number { 2 }",
+            "ExpressionStmt LiteralExpr Number { 3 }",
+        ];
+        perform_repl("1 + 2; 3", expected);
+    }
+
+    #[test]
+    fn repl_semicolon_outside_repl() {
+        perform_err("1 + 2", LoxErrorsTypes::Syntax("Expected ';' after".to_string()));
+        perform_err("1 + 2 3", LoxErrorsTypes::Syntax("Expected ';' after".to_string()));
+    }
+
     #[test]
     fn binary_sub_numbers() {
         let src = "1 - 2;";
@@ -2088,6 +2965,20 @@ mod tests {
         perform(src, expected)
     }
 
+    #[test]
+    fn compound_assign_call() {
+        let src = "a += V(1, 2);";
+        let expected = vec!["ExpressionStmt CompoundAssignExpr a += CallExpr VariableExpr V LiteralExpr Number { 1 } LiteralExpr Number { 2 }"];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn compound_assign_property_binary() {
+        let src = "a.b *= c + 1;";
+        let expected = vec!["ExpressionStmt SetExpr VariableExpr a -> b = BinaryExpr VariableExpr c + LiteralExpr Number { 1 }"];
+        perform(src, expected)
+    }
+
     #[test]
     fn lambda_fn() {
         let src = "lm() {};";
@@ -2111,6 +3002,20 @@ mod tests {
         perform(src, expected)
     }
 
+    #[test]
+    fn lambda_fn_arrow() {
+        let src = "lm(a, b) => a + b;";
+        let expected = vec!["ExpressionStmt LambdaExpr lm(a, b) { ReturnStmt BinaryExpr VariableExpr a + VariableExpr b }"];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn lambda_fn_arrow_nested() {
+        let src = "lm(a) => lm(b) => a * b;";
+        let expected = vec!["ExpressionStmt LambdaExpr lm(a) { ReturnStmt LambdaExpr lm(b) { ReturnStmt BinaryExpr VariableExpr a * VariableExpr b } }"];
+        perform(src, expected)
+    }
+
     #[test]
     fn ternary() {
         let src = "true ? true : false;";
@@ -3295,6 +4200,39 @@ mod tests {
         perform_err(src, expected)
     }
 
+    #[test]
+    fn index_expr_negative() {
+        let src = "a[-1];";
+        let expected = vec!["ExpressionStmt IndexExpr VariableExpr a UnaryExpr - LiteralExpr Number { 1 }"];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn index_expr_slice() {
+        let src = "a[1:4]; a[:3]; a[::2]; a[1:];";
+        let expected = vec![
+            "ExpressionStmt IndexExpr VariableExpr a SliceExpr LiteralExpr Number { 1 }:LiteralExpr Number { 4 }:",
+            "ExpressionStmt IndexExpr VariableExpr a SliceExpr :LiteralExpr Number { 3 }:",
+            "ExpressionStmt IndexExpr VariableExpr a SliceExpr ::LiteralExpr Number { 2 }",
+            "ExpressionStmt IndexExpr VariableExpr a SliceExpr LiteralExpr Number { 1 }::",
+        ];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn index_expr_slice_assignment() {
+        let src = "a[1:3] = [9];";
+        let expected = vec!["ExpressionStmt UpdateIndex VariableExpr a[SliceExpr LiteralExpr Number { 1 }:LiteralExpr Number { 3 }: = ArrayExpr [LiteralExpr Number { 9 }]]"];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn index_expr_slice_unclosed() {
+        let src = "a[1:2;";
+        let expected = LoxErrorsTypes::Syntax("Expected ']' after".to_string());
+        perform_err(src, expected)
+    }
+
     #[test]
     fn update_expr_prefix() {
         let src = "++a;";
@@ -3402,6 +4340,116 @@ mod tests {
         perform(src, expected)
     }
 
+    #[test]
+    fn class_decl_lambda_this() {
+        let src = "class Counter { adder() { return lm(n) => this.x + n; } }";
+        let expected = vec!["ClassStmt Counter { FunctionStmt adder() { ReturnStmt LambdaExpr lm(n) { ReturnStmt BinaryExpr GetExpr ThisExpr -> x + VariableExpr n } } }"];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn bound_method_value() {
+        let src = "let f = obj.method; f();";
+        let expected = vec![
+            "LetStmt f = GetExpr VariableExpr obj -> method",
+            "ExpressionStmt CallExpr VariableExpr f",
+        ];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn class_decl_accessors() {
+        let src = "class Circle { get area() { return 1; } set area(v) {} }";
+        let expected = vec!["ClassStmt Circle { get FunctionStmt area() { ReturnStmt LiteralExpr Number { 1 } }set FunctionStmt area(v) {  } }"];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn class_decl_getter_params() {
+        let src = "class Circle { get area(a) {} }";
+        let expected = LoxErrorsTypes::Syntax("Expected getter to take 0 parameters but got 1 for".to_string());
+        perform_err(src, expected)
+    }
+
+    #[test]
+    fn class_decl_setter_params() {
+        let src = "class Circle { set area() {} }";
+        let expected = LoxErrorsTypes::Syntax("Expected setter to take 1 parameters but got 0 for".to_string());
+        perform_err(src, expected)
+    }
+
+    #[test]
+    fn class_decl_method_named_get() {
+        let src = "class Circle { get() {} }";
+        let expected = vec!["ClassStmt Circle { FunctionStmt get() {  } }"];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn enum_decl() {
+        let src = "enum Shape { Circle(r), Rect(w, h), Empty, }";
+        let expected = vec!["EnumStmt Shape { VariantStmt Circle(r) VariantStmt Rect(w, h) VariantStmt Empty }"];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn enum_decl_missing_comma() {
+        let src = "enum Shape { Circle(r) Empty }";
+        let expected = LoxErrorsTypes::Syntax("Expected '}' after enum body".to_string());
+        perform_err(src, expected)
+    }
+
+    #[test]
+    fn enum_decl_no_name() {
+        let src = "enum { Empty }";
+        let expected = LoxErrorsTypes::Syntax("Expected identifier for enum".to_string());
+        perform_err(src, expected)
+    }
+
+    #[test]
+    fn interface_decl() {
+        let src = "interface Shape { area(); scale(x, y); }";
+        let expected = vec!["InterfaceStmt Shape { SignatureStmt area() SignatureStmt scale(x, y) }"];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn interface_decl_method_body() {
+        let src = "interface Shape { area() {} }";
+        let expected = LoxErrorsTypes::Syntax("Expected ';' after".to_string());
+        perform_err(src, expected)
+    }
+
+    #[test]
+    fn class_decl_implements() {
+        let src = "class Square implements Shape, Named { public area() {} }";
+        let expected = vec!["ClassStmt Square implements Shape, Named { FunctionStmt area() {  } }"];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn class_decl_abstract() {
+        let src = "abstract class Shape { public abstract area(); }";
+        let expected = vec!["ClassStmt abstract Shape { SignatureStmt area() }"];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn class_decl_abstract_method_in_class() {
+        let src = "class Shape { abstract area(); }";
+        let expected = LoxErrorsTypes::Syntax(
+            "Abstract methods are only allowed in abstract classes, found".to_string(),
+        );
+        perform_err(src, expected)
+    }
+
+    #[test]
+    fn class_decl_abstract_no_class() {
+        let src = "abstract Shape {}";
+        let expected = LoxErrorsTypes::Syntax("Expected 'class' after".to_string());
+        perform_err(src, expected)
+    }
+
     #[test]
     fn class_decl_no_name() {
         let src = "class;";
@@ -3464,4 +4512,226 @@ mod tests {
         let expected = LoxErrorsTypes::Syntax("Expected '}' after block".to_string());
         perform_err(src, expected)
     }
+
+    #[test]
+    fn match_expr() {
+        let src = "match (x) { 1 | 2 => a, [b, _] => b, 1..=5 => c, _ => { print(d); } }";
+        let expected = vec!["ExpressionStmt MatchExpr VariableExpr x { 1 | 2 => ExpressionStmt VariableExpr a, [b, _] => ExpressionStmt VariableExpr b, 1..=5 => ExpressionStmt VariableExpr c, _ => BlockStmt { ExpressionStmt CallExpr VariableExpr print VariableExpr d } }"];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn match_expr_variant_guard() {
+        let src = "let area = match (s) { Shape.Circle(r) if r > 1 => r, Shape.Empty => -1, };";
+        let expected = vec!["LetStmt area = MatchExpr VariableExpr s { Shape.Circle(r) if BinaryExpr VariableExpr r > LiteralExpr Number { 1 } => ExpressionStmt VariableExpr r, Shape.Empty => ExpressionStmt UnaryExpr - LiteralExpr Number { 1 } }"];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn match_expr_no_arms() {
+        let src = "match (x) {}";
+        let expected = LoxErrorsTypes::Syntax("Expected at least one arm for".to_string());
+        perform_err(src, expected)
+    }
+
+    #[test]
+    fn match_expr_no_arrow() {
+        let src = "match (x) { 1 -> a }";
+        let expected = LoxErrorsTypes::Syntax("Expected '=>' after pattern".to_string());
+        perform_err(src, expected)
+    }
+
+    #[test]
+    fn match_expr_bad_range() {
+        let src = "match (x) { \"a\"..\"z\" => a }";
+        let expected = LoxErrorsTypes::Syntax("Expected numbers as range bounds for".to_string());
+        perform_err(src, expected)
+    }
+
+    #[test]
+    fn let_destructure_array() {
+        let src = "let [a, b, ...rest] = arr;";
+        let expected = vec!["DestructureStmt [a, b, ...rest] = VariableExpr arr"];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn let_destructure_object() {
+        let src = "let { name, age } = person;";
+        let expected = vec!["DestructureStmt { name, age } = VariableExpr person"];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn let_destructure_rest_not_last() {
+        let src = "let [...rest, a] = arr;";
+        let expected = LoxErrorsTypes::Syntax("Rest element must be last, found".to_string());
+        perform_err(src, expected)
+    }
+
+    #[test]
+    fn let_destructure_no_initializer() {
+        let src = "let [a, b];";
+        let expected =
+            LoxErrorsTypes::Syntax("Expected '=' after destructuring pattern".to_string());
+        perform_err(src, expected)
+    }
+
+    #[test]
+    fn fn_decl_destructure_param() {
+        let src = "fn first([h, ...t], k) { return h; }";
+        let expected = vec![
+            "FunctionStmt first([h, ...t], k) { DestructureStmt [h, ...t] = VariableExpr [h, ...t] ReturnStmt VariableExpr h }",
+        ];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn fn_decl_default_params() {
+        let src = "fn greet(name, greeting = \"Hello\") {}";
+        let expected = vec![
+            "FunctionStmt greet(name, greeting = LiteralExpr String { \"Hello\" }) {  }",
+        ];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn fn_decl_default_before_required() {
+        let src = "fn greet(greeting = \"Hello\", name) {}";
+        let expected = LoxErrorsTypes::Syntax(
+            "Expected a default value for parameter after optional parameters, found".to_string(),
+        );
+        perform_err(src, expected)
+    }
+
+    #[test]
+    fn lambda_default_params() {
+        let src = "let add = lm(a, b = 1) { return a + b; };";
+        let expected = vec![
+            "LetStmt add = LambdaExpr lm(a, b = LiteralExpr Number { 1 }) { ReturnStmt BinaryExpr VariableExpr a + VariableExpr b }",
+        ];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn call_keyword_args() {
+        let src = "greet(\"Ana\", greeting: \"Hi\");";
+        let expected = vec![
+            "ExpressionStmt CallExpr VariableExpr greet LiteralExpr String { \"Ana\" } greeting: LiteralExpr String { \"Hi\" }",
+        ];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn call_positional_after_keyword() {
+        let src = "greet(greeting: \"Hi\", \"Ana\");";
+        let expected = LoxErrorsTypes::Syntax(
+            "Expected keyword argument after keyword arguments, found".to_string(),
+        );
+        perform_err(src, expected)
+    }
+
+    #[test]
+    fn fn_decl_rest_param() {
+        let src = "fn sum(first, ...nums) {}";
+        let expected = vec!["FunctionStmt sum(first, ...nums) {  }"];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn fn_decl_rest_param_not_last() {
+        let src = "fn sum(...nums, last) {}";
+        let expected = LoxErrorsTypes::Syntax("Rest parameter must be last, found".to_string());
+        perform_err(src, expected)
+    }
+
+    #[test]
+    fn call_spread_args() {
+        let src = "sum(1, ...arr);";
+        let expected = vec![
+            "ExpressionStmt CallExpr VariableExpr sum LiteralExpr Number { 1 } SpreadExpr ...VariableExpr arr",
+        ];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn array_expr_spread() {
+        let src = "[...a, ...b];";
+        let expected = vec![
+            "ExpressionStmt ArrayExpr [SpreadExpr ...VariableExpr a, SpreadExpr ...VariableExpr b]",
+        ];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn const_decl() {
+        let src = "const MAX = 100;";
+        let expected = vec!["LetStmt const MAX = LiteralExpr Number { 100 }"];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn const_decl_no_initializer() {
+        let src = "const MAX;";
+        let expected = LoxErrorsTypes::Syntax("Expected initializer for constant".to_string());
+        perform_err(src, expected)
+    }
+
+    #[test]
+    fn class_decl_const_field() {
+        let src = "class Config { public const MAX = 100; }";
+        let expected = vec!["ClassStmt Config {  }"];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn class_decl_const_field_no_initializer() {
+        let src = "class Config { const MAX; }";
+        let expected =
+            LoxErrorsTypes::Syntax("Expected initializer for constant field".to_string());
+        perform_err(src, expected)
+    }
+
+    #[test]
+    fn optional_chaining() {
+        let src = "a?.b.c;";
+        let expected = vec![
+            "ExpressionStmt ChainExpr GetExpr GetExpr OptionalExpr VariableExpr a -> b -> c",
+        ];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn optional_chaining_call_and_index() {
+        let src = "a?.method()?.[0];";
+        let expected = vec![
+            "ExpressionStmt ChainExpr IndexExpr OptionalExpr CallExpr GetExpr OptionalExpr VariableExpr a -> method LiteralExpr Number { 0 }",
+        ];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn optional_chaining_assignment() {
+        let src = "a?.b = 1;";
+        let expected = LoxErrorsTypes::Parse("Invalid assignment target".to_string());
+        perform_err(src, expected)
+    }
+
+    #[test]
+    fn null_coalescing() {
+        let src = "a ?? b or c;";
+        let expected = vec![
+            "ExpressionStmt LogicalExpr VariableExpr a ?? LogicalExpr VariableExpr b or VariableExpr c",
+        ];
+        perform(src, expected)
+    }
+
+    #[test]
+    fn null_coalescing_assignment() {
+        let src = "a ??= 1;";
+        let expected = vec![
+            "ExpressionStmt LogicalExpr VariableExpr a ?? AssignExpr a = LiteralExpr Number { 1 }",
+        ];
+        perform(src, expected)
+    }
 }
@@ -9,6 +9,21 @@ use crate::{error::*, lexer::literal::Literal};
 pub trait LoxCallable {
     fn arity(&self) -> usize;
     fn call(&self, interpreter: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult>;
+
+    // Natives taking keyword arguments, like `format`, opt in here and
+    // receive them by name in `call_keywords`.
+    fn accepts_keywords(&self) -> bool {
+        false
+    }
+
+    fn call_keywords(
+        &self,
+        interpreter: Option<&Interpreter>,
+        args: Vec<Literal>,
+        _keywords: Vec<(String, Literal)>,
+    ) -> Result<Literal, LoxResult> {
+        self.call(interpreter, args)
+    }
 }
 
 #[derive(Clone)]
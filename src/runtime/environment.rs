@@ -10,6 +10,7 @@ pub struct Environment {
     pub id: usize,
     env: HashMap<String, Literal>,
     natives: HashMap<String, ()>,
+    constants: HashMap<String, ()>,
     error_handler: LoxErrorHandler,
     enclosing: Option<Rc<RefCell<Environment>>>,
 }
@@ -21,6 +22,7 @@ impl Environment {
             error_handler: LoxErrorHandler::new(),
             enclosing: None,
             natives: HashMap::new(),
+            constants: HashMap::new(),
             id: 0,
         }
     }
@@ -32,6 +34,7 @@ impl Environment {
             error_handler: LoxErrorHandler::new(),
             enclosing: Some(env),
             natives: HashMap::new(),
+            constants: HashMap::new(),
             id,
         }
     }
@@ -59,6 +62,12 @@ impl Environment {
         Ok(())
     }
 
+    pub fn define_const(&mut self, name: &Token, val: Literal) -> Result<(), LoxResult> {
+        self.define(name, val)?;
+        self.constants.insert(name.lexeme.to_string(), ());
+        Ok(())
+    }
+
     pub fn mutate(&mut self, name: &Token, val: Literal) -> Result<(), LoxResult> {
         if self.natives.contains_key(&name.lexeme) {
             return Err(self.error_handler.error(
@@ -67,6 +76,12 @@ impl Environment {
             ));
         }
         if self.env.contains_key(&name.lexeme) {
+            if self.constants.contains_key(&name.lexeme) {
+                return Err(self.error_handler.error(
+                    name,
+                    LoxErrorsTypes::Type("Cannot assign to constant".to_string()),
+                ));
+            }
             self.env.insert(name.lexeme.to_string(), val);
             return Ok(());
         } else if let Some(enclosing) = &self.enclosing {
@@ -1,16 +1,23 @@
 use super::{
     callable::LoxCallable, environment::Environment, load::load, loxclass::LoxClass,
-    loxfunction::LoxFunction, loxinstance::InstanceField,
+    loxenum::{EnumVariant, LoxEnum},
+    loxfunction::LoxFunction, loxinstance::{InstanceField, LoxInstance},
 };
 use crate::{
     error::{loxerrorhandler::LoxErrorHandler, LoxError, LoxErrorsTypes, LoxResult},
     lexer::{literal::*, token::*, tokentype::TokenType},
     loxlib::string::loxstring::LoxString,
     loxlib::{array::loxarray::LoxArray, number::loxnumber::LoxNumber},
-    parser::{expr::*, stmt::*},
+    parser::{
+        expr::*,
+        pattern::{Destructure, MatchArm, Pattern},
+        stmt::*,
+    },
 };
 use std::{
     cell::RefCell,
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
     ops::{Add, Div, Mul, Sub},
 };
 use std::{collections::HashMap, rc::Rc};
@@ -18,10 +25,13 @@ use std::{collections::HashMap, rc::Rc};
 pub struct Interpreter {
     pub globals: Rc<RefCell<Environment>>,
     pub locals: RefCell<HashMap<Rc<Expr>, usize>>,
+    // Class declarations each property access is lexically inside of.
+    access: RefCell<HashMap<Rc<Expr>, Vec<Rc<Stmt>>>>,
+    // Method names and arities of the top level interfaces, kept between REPL lines.
+    interfaces: RefCell<HashMap<String, Vec<(String, usize)>>>,
     pub environment: RefCell<Rc<RefCell<Environment>>>,
     pub error_handler: LoxErrorHandler,
     is_repl: bool,
-    is_single_expr: RefCell<bool>,
 }
 
 impl Interpreter {
@@ -34,32 +44,52 @@ impl Interpreter {
         Self {
             globals: Rc::clone(&globals),
             locals: RefCell::new(HashMap::new()),
+            access: RefCell::new(HashMap::new()),
+            interfaces: RefCell::new(HashMap::new()),
             error_handler: LoxErrorHandler::new(),
             environment: RefCell::new(Rc::clone(&globals)),
             is_repl: false,
-            is_single_expr: RefCell::new(false),
         }
     }
 
     pub fn interpret(&mut self, stmts: Vec<Rc<Stmt>>) -> Result<(), LoxResult> {
-        if stmts.len() == 1 {
-            if let Some(stmt) = stmts.get(0) {
-                match **stmt {
-                    Stmt::Expression(_) => self.is_single_expr.replace(true),
-                    _ => self.is_single_expr.replace(false),
-                };
+        for stmt in stmts {
+            match &*stmt {
+                Stmt::Expression(s) if self.is_repl => self.echo(s.expr.clone())?,
+                _ => self.execute(stmt)?,
             }
         }
-        for stmt in stmts {
-            self.execute(stmt)?;
+        Ok(())
+    }
+
+    fn echo(&self, expr: Rc<Expr>) -> Result<(), LoxResult> {
+        let val = self.evaluate(expr.clone())?;
+        let is_assignment = matches!(
+            *expr,
+            Expr::Assign(_) | Expr::CompoundAssign(_) | Expr::Set(_) | Expr::UpdateIndex(_)
+        );
+        if !is_assignment && !matches!(val, Literal::None | Literal::LiteralNone) {
+            println!("{}", self.repr(&val)?);
         }
         Ok(())
     }
+
     pub fn evaluate(&self, expr: Rc<Expr>) -> Result<Literal, LoxResult> {
         let val = expr.accept(expr.clone(), self, 0_u16)?;
         Ok(val)
     }
 
+    pub fn evaluate_in(
+        &self,
+        expr: Rc<Expr>,
+        env: Rc<RefCell<Environment>>,
+    ) -> Result<Literal, LoxResult> {
+        let prev = self.environment.replace(env);
+        let val = self.evaluate(expr);
+        self.environment.replace(prev);
+        val
+    }
+
     pub fn execute(&self, stmt: Rc<Stmt>) -> Result<(), LoxResult> {
         stmt.accept(stmt.clone(), self, 0_u16)
     }
@@ -68,12 +98,406 @@ impl Interpreter {
         self.locals.borrow_mut().insert(expr, depth);
     }
 
+    pub fn resolve_access(&self, expr: Rc<Expr>, classes: Vec<Rc<Stmt>>) {
+        self.access.borrow_mut().insert(expr, classes);
+    }
+
+    pub fn declare_interface(&self, name: &str, methods: Vec<(String, usize)>) {
+        self.interfaces.borrow_mut().insert(name.to_string(), methods);
+    }
+
+    pub fn interface(&self, name: &str) -> Option<Vec<(String, usize)>> {
+        self.interfaces.borrow().get(name).cloned()
+    }
+
+    fn private_access(&self, expr: &Rc<Expr>, instance: &LoxInstance) -> bool {
+        match self.access.borrow().get(expr) {
+            Some(classes) => instance.is_declared_by(classes),
+            None => false,
+        }
+    }
+
     pub fn is_truthy(&self, right: &Literal) -> bool {
         !matches!(right, Literal::None | Literal::Bool(false))
     }
 
-    pub fn is_equal(&self, left: Literal, right: Literal) -> bool {
-        left.equals(right)
+    pub fn is_equal(&self, left: &Literal, right: &Literal) -> Result<bool, LoxResult> {
+        self.values_equal(left, right, &mut Vec::new())
+    }
+
+    // Instances compare with `__eq__` when defined and by identity otherwise,
+    // when only the right operand defines `__eq__` it is asked instead so that
+    // `a == b` and `b == a` agree. Arrays compare element by element, `seen`
+    // holds the pairs of arrays being compared so that self referencing arrays
+    // terminate.
+    fn values_equal(
+        &self,
+        left: &Literal,
+        right: &Literal,
+        seen: &mut Vec<(*const (), *const ())>,
+    ) -> Result<bool, LoxResult> {
+        match (left, right) {
+            (Literal::Instance(instance), _) => {
+                if let Some(val) = self.call_special(instance, "__eq__", vec![right.dup()])? {
+                    return Ok(self.is_truthy(&val));
+                }
+                match right {
+                    Literal::Instance(other) => {
+                        if let Some(val) = self.call_special(other, "__eq__", vec![left.dup()])? {
+                            return Ok(self.is_truthy(&val));
+                        }
+                        Ok(Rc::ptr_eq(instance, other))
+                    }
+                    _ => Ok(false),
+                }
+            }
+            (_, Literal::Instance(instance)) => {
+                match self.call_special(instance, "__eq__", vec![left.dup()])? {
+                    Some(val) => Ok(self.is_truthy(&val)),
+                    None => Ok(false),
+                }
+            }
+            (Literal::Array(a), Literal::Array(b)) => {
+                let pair = (
+                    Rc::as_ptr(&a.array) as *const (),
+                    Rc::as_ptr(&b.array) as *const (),
+                );
+                if pair.0 == pair.1 || seen.contains(&pair) {
+                    return Ok(true);
+                }
+                let a = a.array.borrow().clone();
+                let b = b.array.borrow().clone();
+                if a.len() != b.len() {
+                    return Ok(false);
+                }
+
+                seen.push(pair);
+                let mut equal = true;
+                for (x, y) in a.iter().zip(b.iter()) {
+                    if !self.values_equal(x, y, seen)? {
+                        equal = false;
+                        break;
+                    }
+                }
+                seen.pop();
+                Ok(equal)
+            }
+            (Literal::Number(a), Literal::Number(b)) => Ok(*a.num.borrow() == *b.num.borrow()),
+            (Literal::Str(a), Literal::Str(b)) => Ok(*a.string.borrow() == *b.string.borrow()),
+            (Literal::Bool(a), Literal::Bool(b)) => Ok(a == b),
+            (
+                Literal::None | Literal::LiteralNone,
+                Literal::None | Literal::LiteralNone,
+            ) => Ok(true),
+            (Literal::Func(a), Literal::Func(b)) => Ok(Rc::ptr_eq(a, b)),
+            (Literal::Native(a), Literal::Native(b)) => Ok(a == b),
+            (Literal::Class(a), Literal::Class(b)) => Ok(Rc::ptr_eq(a, b)),
+            (Literal::Enum(a), Literal::Enum(b)) => Ok(Rc::ptr_eq(a, b)),
+            (Literal::Variant(a), Literal::Variant(b)) => {
+                if !Rc::ptr_eq(&a.owner, &b.owner) || a.name != b.name {
+                    return Ok(false);
+                }
+                for (x, y) in a.values.iter().zip(b.values.iter()) {
+                    if !self.values_equal(x, y, seen)? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    pub fn hash_value(&self, val: &Literal) -> Result<u64, LoxResult> {
+        let mut hasher = DefaultHasher::new();
+        self.hash_into(val, &mut hasher, &mut Vec::new())?;
+        Ok(hasher.finish())
+    }
+
+    // Mirrors `values_equal` so that values which compare equal hash the same.
+    // `0` and `-0` are equal so they must hash the same.
+    fn hash_number(num: f64, hasher: &mut DefaultHasher) {
+        let num = if num == 0.0 { 0.0 } else { num };
+        num.to_bits().hash(hasher);
+    }
+
+    fn hash_into(
+        &self,
+        val: &Literal,
+        hasher: &mut DefaultHasher,
+        seen: &mut Vec<*const ()>,
+    ) -> Result<(), LoxResult> {
+        match val {
+            Literal::Number(num) => Interpreter::hash_number(*num.num.borrow(), hasher),
+            Literal::Str(str) => str.string.borrow().hash(hasher),
+            Literal::Bool(bool) => bool.hash(hasher),
+            Literal::None | Literal::LiteralNone => hasher.write_u8(0),
+            Literal::Array(arr) => {
+                let ptr = Rc::as_ptr(&arr.array) as *const ();
+                if seen.contains(&ptr) {
+                    return Ok(());
+                }
+                let elems = arr.array.borrow().clone();
+                elems.len().hash(hasher);
+                seen.push(ptr);
+                for el in elems.iter() {
+                    self.hash_into(el, hasher, seen)?;
+                }
+                seen.pop();
+            }
+            Literal::Instance(instance) => {
+                match self.call_special(instance, "__hash__", Vec::new())? {
+                    Some(Literal::Number(num)) => Interpreter::hash_number(*num.num.borrow(), hasher),
+                    Some(other) => {
+                        return Err(LoxResult::Message(format!(
+                            "Expected '__hash__' of '{}' to return 'Number' but got '{}'",
+                            instance.class_name(),
+                            other.get_typename()
+                        )))
+                    }
+                    None if self.find_special_method(instance, "__eq__").is_some() => {
+                        return Err(LoxResult::Message(format!(
+                            "Instances of '{}' are unhashable, '__eq__' is defined without '__hash__'",
+                            instance.class_name()
+                        )))
+                    }
+                    None => (Rc::as_ptr(instance) as usize).hash(hasher),
+                }
+            }
+            Literal::Func(func) => (Rc::as_ptr(func) as usize).hash(hasher),
+            Literal::Native(n) => (Rc::as_ptr(&n.native) as *const () as usize).hash(hasher),
+            Literal::Class(class) => (Rc::as_ptr(class) as usize).hash(hasher),
+            Literal::Enum(e) => (Rc::as_ptr(e) as usize).hash(hasher),
+            Literal::Variant(variant) => {
+                variant.name.hash(hasher);
+                for val in variant.values.iter() {
+                    self.hash_into(val, hasher, seen)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn stringify(&self, val: &Literal) -> Result<String, LoxResult> {
+        let failed = RefCell::new(None);
+        let str = val.get_value_with(&|i| self.call_to_string(i, &failed));
+        match failed.into_inner() {
+            Some(err) => Err(err),
+            None => Ok(str),
+        }
+    }
+
+    pub fn repr(&self, val: &Literal) -> Result<String, LoxResult> {
+        let failed = RefCell::new(None);
+        let str = val.repr_with(&|i| self.call_to_string(i, &failed));
+        match failed.into_inner() {
+            Some(err) => Err(err),
+            None => Ok(str),
+        }
+    }
+
+    fn call_to_string(
+        &self,
+        instance: &Rc<LoxInstance>,
+        failed: &RefCell<Option<LoxResult>>,
+    ) -> Option<String> {
+        if failed.borrow().is_some() {
+            return None;
+        }
+        match self.instance_to_string(instance) {
+            Ok(str) => str,
+            Err(err) => {
+                failed.replace(Some(err));
+                None
+            }
+        }
+    }
+
+    fn instance_to_string(&self, instance: &Rc<LoxInstance>) -> Result<Option<String>, LoxResult> {
+        let method = match self.find_special_method(instance, "toString") {
+            Some(method) => method,
+            None => return Ok(None),
+        };
+        let name = method.name.clone().expect("class methods are always named");
+        if method.arity() != 0 {
+            return Err(self.error_handler.error(
+                &name,
+                LoxErrorsTypes::Runtime("Expected no parameters for".to_string()),
+            ));
+        }
+
+        match method.bind(instance.clone())?.call(Some(self), Vec::new())? {
+            Literal::Str(str) => Ok(Some(str.string.borrow().to_string())),
+            other => Err(self.error_handler.error(
+                &name,
+                LoxErrorsTypes::Type(format!(
+                    "Expected 'String' to be returned but got '{}' from",
+                    other.get_typename()
+                )),
+            )),
+        }
+    }
+
+    fn find_special_method(&self, instance: &Rc<LoxInstance>, name: &str) -> Option<Rc<LoxFunction>> {
+        match instance.find_method(name) {
+            Some(Literal::Func(method)) if !method.is_static => Some(method),
+            _ => None,
+        }
+    }
+
+    fn operator_method(operator: TokenType) -> Option<&'static str> {
+        match operator {
+            TokenType::Plus | TokenType::PlusEqual | TokenType::PlusPlus => Some("__add__"),
+            TokenType::Minus | TokenType::MinusEqual | TokenType::MinusMinus => Some("__sub__"),
+            TokenType::Star | TokenType::StarEqual => Some("__mul__"),
+            TokenType::Slash | TokenType::SlashEqual => Some("__div__"),
+            TokenType::Modulus | TokenType::ModEqual => Some("__mod__"),
+            _ => None,
+        }
+    }
+
+    fn call_operator(
+        &self,
+        instance: &Rc<LoxInstance>,
+        name: &str,
+        operator: &Token,
+        args: Vec<Literal>,
+    ) -> Result<Option<Literal>, LoxResult> {
+        let method = match self.find_special_method(instance, name) {
+            Some(method) => method,
+            None => return Ok(None),
+        };
+        if method.arity() != args.len() {
+            return Err(self.error_handler.error(
+                operator,
+                LoxErrorsTypes::Runtime(format!(
+                    "Expected '{}' to take {} arguments but it takes {} for",
+                    name,
+                    args.len(),
+                    method.arity()
+                )),
+            ));
+        }
+        let result = method.bind(instance.clone())?.call(Some(self), args);
+        Ok(Some(self.catch_message(operator, result)?))
+    }
+
+    // Calls a function value on behalf of a native, like the callback given to `Array.map`.
+    // Errors raised inside the callback are already reported at their own line.
+    pub fn call_value(&self, callee: &Literal, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        let (expected, takes) = match callee {
+            Literal::Func(func) => {
+                let arity = func.required_arity()..=func.fixed_arity();
+                if arity.contains(&args.len()) || (func.is_variadic && args.len() > *arity.end()) {
+                    return func.call(Some(self), args);
+                }
+                (args.len(), func.fixed_arity())
+            }
+            Literal::Native(func) => {
+                if !func.check_arity || func.native.arity() == args.len() {
+                    return func.native.call(Some(self), args);
+                }
+                (args.len(), func.native.arity())
+            }
+            Literal::Class(class) if !class.is_abstract() => {
+                if class.is_variadic() || class.arity() == args.len() {
+                    return class.call(Some(self), args);
+                }
+                (args.len(), class.arity())
+            }
+            _ => {
+                return Err(LoxResult::Message(format!(
+                    "Expected type 'Function' for callback got '{}'",
+                    callee.get_typename()
+                )))
+            }
+        };
+        Err(LoxResult::Message(format!(
+            "Expected callback to take {expected} arguments but it takes {takes}"
+        )))
+    }
+
+    fn accessors(&self, stmts: &[Rc<Stmt>]) -> HashMap<String, Rc<LoxFunction>> {
+        let mut accessors = HashMap::new();
+        for stmt in stmts.iter() {
+            match &**stmt {
+                Stmt::Function(f) => {
+                    let func = LoxFunction::new(
+                        f,
+                        &self.environment.borrow(),
+                        false,
+                        f.is_static,
+                        f.is_pub,
+                    );
+                    accessors.insert(f.name.lexeme.to_string(), Rc::new(func));
+                }
+                _ => panic!("unexpected statement {stmt:?}"),
+            }
+        }
+        accessors
+    }
+
+    // Calls a special method the user did not invoke directly, errors are
+    // reported at the method declaration.
+    fn call_special(
+        &self,
+        instance: &Rc<LoxInstance>,
+        name: &str,
+        args: Vec<Literal>,
+    ) -> Result<Option<Literal>, LoxResult> {
+        let token = match self.find_special_method(instance, name) {
+            Some(method) => method.name.clone().expect("class methods are always named"),
+            None => return Ok(None),
+        };
+        self.call_operator(instance, name, &token, args)
+    }
+
+    // Dispatches a binary operator to the matching method of the left operand,
+    // `None` means the class does not overload it. `<=`, `>` and `>=` are derived
+    // from `__lt__` and equality, `==` and `!=` are handled by `is_equal`.
+    fn binary_overload(
+        &self,
+        instance: &Rc<LoxInstance>,
+        operator: &Token,
+        right: Literal,
+    ) -> Result<Option<Literal>, LoxResult> {
+        match operator.token_type {
+            TokenType::Less
+            | TokenType::GreaterEqual
+            | TokenType::LessEqual
+            | TokenType::Greater => {
+                let less = match self.call_operator(instance, "__lt__", operator, vec![right.dup()])? {
+                    Some(val) => self.is_truthy(&val),
+                    None => return Ok(None),
+                };
+                let this = Literal::Instance(instance.clone());
+                let result = match operator.token_type {
+                    TokenType::Less => less,
+                    TokenType::GreaterEqual => !less,
+                    TokenType::LessEqual => less || self.is_equal(&this, &right)?,
+                    _ => !(less || self.is_equal(&this, &right)?),
+                };
+                Ok(Some(Literal::Bool(result)))
+            }
+            _ => match Interpreter::operator_method(operator.token_type) {
+                Some(name) => self.call_operator(instance, name, operator, vec![right]),
+                None => Ok(None),
+            },
+        }
+    }
+
+    fn coerce_concat(&self, left: Literal, right: Literal) -> Result<(Literal, Literal), LoxResult> {
+        match (&left, &right) {
+            (Literal::Str(_), Literal::Instance(_)) => {
+                let str = self.stringify(&right)?;
+                Ok((left, Literal::Str(Rc::new(LoxString::new(str)))))
+            }
+            (Literal::Instance(_), Literal::Str(_)) => {
+                let str = self.stringify(&left)?;
+                Ok((Literal::Str(Rc::new(LoxString::new(str))), right))
+            }
+            _ => Ok((left, right)),
+        }
     }
 
     fn look_up_variable(&self, name: &Token, expr: &Rc<Expr>) -> Result<Literal, LoxResult> {
@@ -111,7 +535,14 @@ impl Interpreter {
         right: &Literal,
     ) -> Result<(), LoxResult> {
         match operator.token_type {
-            TokenType::Minus | TokenType::Slash | TokenType::Star | TokenType::Modulus => {
+            TokenType::Minus
+            | TokenType::Slash
+            | TokenType::Star
+            | TokenType::Modulus
+            | TokenType::Greater
+            | TokenType::Less
+            | TokenType::GreaterEqual
+            | TokenType::LessEqual => {
                 if left.get_typename() == "Number" && right.get_typename() == "Number" {
                     return Ok(());
                 }
@@ -246,12 +677,48 @@ impl Interpreter {
         Ok(())
     }
 
+    // Value passed to a setter, compound assignments read the current value
+    // through the getter.
+    fn accessor_value(
+        &self,
+        expr: &SetExpr,
+        getter: impl FnOnce() -> Result<Option<Rc<LoxFunction>>, LoxResult>,
+    ) -> Result<Literal, LoxResult> {
+        if expr.operator.token_type == TokenType::Assign {
+            return self.evaluate(expr.value.clone());
+        }
+        let getter = getter()?;
+        let new = self.evaluate(expr.value.clone())?;
+        match getter {
+            Some(getter) => {
+                let current = getter.call(Some(self), Vec::new())?;
+                self.operate_compound_set(&expr.operator, current, new)
+            }
+            None => Err(self.error_handler.error(
+                &expr.name,
+                LoxErrorsTypes::ReferenceError("Cannot get write-only property".to_string()),
+            )),
+        }
+    }
+
     fn operate_compound_set(
         &self,
         operator: &Token,
         a: Literal,
         b: Literal,
     ) -> Result<Literal, LoxResult> {
+        if let Literal::Instance(instance) = &a {
+            if operator.token_type != TokenType::Assign {
+                if let Some(val) = self.binary_overload(instance, operator, b.dup())? {
+                    return Ok(val);
+                }
+            }
+        }
+        let (a, b) = if operator.token_type == TokenType::PlusEqual {
+            self.coerce_concat(a, b)?
+        } else {
+            (a, b)
+        };
         self.check_compound_arithmetic(operator, &a, &b)?;
         match operator.token_type {
             TokenType::PlusEqual => {
@@ -323,6 +790,134 @@ impl Interpreter {
         Ok(index as usize)
     }
 
+    // Normalizes the bounds of `[start:end:step]` for a sequence of length `len`,
+    // out of range bounds are clamped and missing ones depend on the direction of `step`.
+    // `len` is read only after the bounds are evaluated, since they can change the array.
+    fn slice_bounds(
+        &self,
+        slice: &SliceExpr,
+        len: impl FnOnce() -> usize,
+    ) -> Result<(isize, isize, isize), LoxResult> {
+        let mut bounds = [None, None, None];
+        for (i, part) in [&slice.start, &slice.end, &slice.step].iter().enumerate() {
+            if let Some(part) = part {
+                let value = self.evaluate(part.clone())?;
+                bounds[i] = Some(self.check_index(&slice.bracket, &value)?);
+            }
+        }
+
+        let step = bounds[2].unwrap_or(1);
+        if step == 0 {
+            return Err(self.error_handler.error(
+                &slice.bracket,
+                LoxErrorsTypes::Runtime("Slice step cannot be zero".to_string()),
+            ));
+        }
+
+        let len = len() as isize;
+        let (low, high) = if step > 0 { (0, len) } else { (-1, len - 1) };
+        let clamp = |bound: isize| {
+            let bound = if bound < 0 { bound + len } else { bound };
+            bound.clamp(low, high)
+        };
+        let start = bounds[0].map_or(if step > 0 { low } else { high }, clamp);
+        let end = bounds[1].map_or(if step > 0 { high } else { low }, clamp);
+        Ok((start, end, step))
+    }
+
+    fn slice_indices(start: isize, end: isize, step: isize) -> Vec<usize> {
+        let mut indices = Vec::new();
+        let mut i = start;
+        while (step > 0 && i < end) || (step < 0 && i > end) {
+            indices.push(i as usize);
+            i += step;
+        }
+        indices
+    }
+
+    fn slice(&self, literal: &Literal, slice: &SliceExpr) -> Result<Literal, LoxResult> {
+        match literal {
+            Literal::Array(arr) => {
+                let (start, end, step) = self.slice_bounds(slice, || arr.array.borrow().len())?;
+                let array = arr.array.borrow();
+                let values = Interpreter::slice_indices(start, end, step)
+                    .into_iter()
+                    .map(|i| array[i].dup())
+                    .collect();
+                Ok(Literal::Array(Rc::new(LoxArray::new(values))))
+            }
+            Literal::Str(str) => {
+                let chars: Vec<char> = str.string.borrow().chars().collect();
+                let (start, end, step) = self.slice_bounds(slice, || chars.len())?;
+                let string = Interpreter::slice_indices(start, end, step)
+                    .into_iter()
+                    .map(|i| chars[i])
+                    .collect();
+                Ok(Literal::Str(Rc::new(LoxString::new(string))))
+            }
+            _ => Err(self.error_handler.error(
+                &slice.bracket,
+                LoxErrorsTypes::Type(format!("Cannot slice '{}' at", literal.get_typename())),
+            )),
+        }
+    }
+
+    // `arr[1:3] = [9]` replaces the elements in the slice with the ones of the assigned array,
+    // slices with a step other than 1 must be assigned the same number of elements.
+    fn assign_slice(&self, literal: &Literal, slice: &SliceExpr, value: &Literal) -> Result<(), LoxResult> {
+        let arr = match literal {
+            Literal::Array(arr) => arr,
+            Literal::Str(_) => {
+                return Err(self.error_handler.error(
+                    &slice.bracket,
+                    LoxErrorsTypes::Type("'String' does not support item assignment".to_string()),
+                ))
+            }
+            _ => {
+                return Err(self.error_handler.error(
+                    &slice.bracket,
+                    LoxErrorsTypes::Type(format!("Cannot slice '{}' at", literal.get_typename())),
+                ))
+            }
+        };
+        let values = match value {
+            Literal::Array(values) => values.array.borrow().clone(),
+            _ => {
+                return Err(self.error_handler.error(
+                    &slice.bracket,
+                    LoxErrorsTypes::Type(format!(
+                        "Expected 'Array' to assign to slice but got '{}' at",
+                        value.get_typename()
+                    )),
+                ))
+            }
+        };
+
+        let (start, end, step) = self.slice_bounds(slice, || arr.array.borrow().len())?;
+        if step == 1 {
+            let (start, end) = (start as usize, end.max(start) as usize);
+            arr.array.borrow_mut().splice(start..end, values);
+            return Ok(());
+        }
+
+        let indices = Interpreter::slice_indices(start, end, step);
+        if indices.len() != values.len() {
+            return Err(self.error_handler.error(
+                &slice.bracket,
+                LoxErrorsTypes::Runtime(format!(
+                    "Expected {} elements to assign to slice but got {} at",
+                    indices.len(),
+                    values.len()
+                )),
+            ));
+        }
+        let mut array = arr.array.borrow_mut();
+        for (i, value) in indices.into_iter().zip(values) {
+            array[i] = value;
+        }
+        Ok(())
+    }
+
     pub fn set_is_repl(&mut self, is: bool) {
         self.is_repl = is;
     }
@@ -399,12 +994,259 @@ impl Interpreter {
         }
     }
 
+    // Bindings are only pushed when the whole pattern matches.
+    fn match_pattern(
+        &self,
+        pattern: &Pattern,
+        value: &Literal,
+        bindings: &mut Vec<(Token, Literal)>,
+    ) -> Result<bool, LoxResult> {
+        match pattern {
+            Pattern::Wildcard(_) => Ok(true),
+            Pattern::Binding(name) => {
+                bindings.push((name.dup(), value.dup()));
+                Ok(true)
+            }
+            Pattern::Literal(_, literal) => self.is_equal(literal, value),
+            Pattern::Range {
+                start,
+                end,
+                inclusive,
+            } => match (start, end, value) {
+                (Literal::Number(start), Literal::Number(end), Literal::Number(num)) => {
+                    let num = *num.num.borrow();
+                    let (start, end) = (*start.num.borrow(), *end.num.borrow());
+                    Ok(start <= num && if *inclusive { num <= end } else { num < end })
+                }
+                _ => Ok(false),
+            },
+            Pattern::Array(_, patterns) => {
+                let arr = match value {
+                    Literal::Array(arr) => arr.array.borrow().clone(),
+                    _ => return Ok(false),
+                };
+                if arr.len() != patterns.len() {
+                    return Ok(false);
+                }
+                let mut matched = Vec::new();
+                for (p, val) in patterns.iter().zip(arr.iter()) {
+                    if !self.match_pattern(p, val, &mut matched)? {
+                        return Ok(false);
+                    }
+                }
+                bindings.append(&mut matched);
+                Ok(true)
+            }
+            Pattern::Variant {
+                enum_expr,
+                name,
+                fields,
+            } => {
+                let owner = match self.evaluate(enum_expr.clone())? {
+                    Literal::Enum(owner) => owner,
+                    val => {
+                        return Err(self.error_handler.error(
+                            name,
+                            LoxErrorsTypes::Type(format!(
+                                "Expected an enum in pattern but found '{}' for",
+                                val.get_typename()
+                            )),
+                        ))
+                    }
+                };
+                let declared = match owner.find_variant(&name.lexeme) {
+                    Some(variant) => variant.fields.as_ref().map_or(0, |f| f.len()),
+                    None => {
+                        return Err(self.error_handler.error(
+                            name,
+                            LoxErrorsTypes::ReferenceError(format!(
+                                "No variant in enum '{}' named",
+                                owner.name
+                            )),
+                        ))
+                    }
+                };
+                if let Some(fields) = fields {
+                    if fields.len() != declared {
+                        return Err(self.error_handler.error(
+                            name,
+                            LoxErrorsTypes::Type(format!(
+                                "Expected {} fields in pattern but got {} for",
+                                declared,
+                                fields.len()
+                            )),
+                        ));
+                    }
+                }
+                let variant = match value {
+                    Literal::Variant(variant)
+                        if Rc::ptr_eq(&variant.owner, &owner) && variant.name == name.lexeme =>
+                    {
+                        variant
+                    }
+                    _ => return Ok(false),
+                };
+                let mut matched = Vec::new();
+                for (p, val) in fields.iter().flatten().zip(variant.values.iter()) {
+                    if !self.match_pattern(p, val, &mut matched)? {
+                        return Ok(false);
+                    }
+                }
+                bindings.append(&mut matched);
+                Ok(true)
+            }
+            Pattern::Or(patterns) => {
+                for p in patterns {
+                    if self.match_pattern(p, value, bindings)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+        }
+    }
+
+    // Returns `None` when the guard rejects the arm.
+    fn execute_arm(&self, arm: &MatchArm) -> Result<Option<Literal>, LoxResult> {
+        if let Some(guard) = &arm.guard {
+            let cond = self.evaluate(guard.clone())?;
+            if !self.is_truthy(&cond) {
+                return Ok(None);
+            }
+        }
+        match &*arm.body {
+            Stmt::Expression(stmt) => Ok(Some(self.evaluate(stmt.expr.clone())?)),
+            _ => {
+                self.execute(arm.body.clone())?;
+                Ok(Some(Literal::None))
+            }
+        }
+    }
+
+    // Places positional and keyword arguments in parameter order, optional
+    // parameters without an argument are left as `None` for their default.
+    fn bind_arguments(
+        &self,
+        func: &LoxFunction,
+        name: &str,
+        expr: &CallExpr,
+        mut args: Vec<Literal>,
+    ) -> Result<Vec<Option<Literal>>, LoxResult> {
+        let params = func.params();
+        let required = func.required_arity();
+        let fixed = func.fixed_arity();
+        let named = args.split_off(args.len() - expr.keywords.len());
+        if (!func.is_variadic && args.len() > fixed) || (named.is_empty() && args.len() < required) {
+            let expected = if func.is_variadic {
+                format!("at least {}", required)
+            } else if required == fixed {
+                required.to_string()
+            } else {
+                format!("{} to {}", required, fixed)
+            };
+            return Err(self.error_handler.error(
+                &expr.paren,
+                LoxErrorsTypes::Runtime(format!(
+                    "Expected {} arguments but got {} for '{}'",
+                    expected,
+                    args.len(),
+                    name
+                )),
+            ));
+        }
+
+        // Arguments past the fixed parameters are collected by the rest parameter
+        let mut slots: Vec<Option<Literal>> = args.into_iter().map(Some).collect();
+        if slots.len() < fixed {
+            slots.resize(fixed, None);
+        }
+        for (keyword, val) in expr.keywords.iter().zip(named) {
+            match params[..fixed].iter().position(|p| p.lexeme == keyword.lexeme) {
+                Some(i) if slots[i].is_none() => slots[i] = Some(val),
+                Some(_) => {
+                    return Err(self.error_handler.error(
+                        keyword,
+                        LoxErrorsTypes::Runtime(format!(
+                            "Multiple values in call to '{}' for parameter",
+                            name
+                        )),
+                    ))
+                }
+                None => {
+                    return Err(self.error_handler.error(
+                        keyword,
+                        LoxErrorsTypes::Runtime(format!("No parameter in '{}' named", name)),
+                    ))
+                }
+            }
+        }
+
+        if let Some(missing) = slots.iter().take(required).position(|s| s.is_none()) {
+            return Err(self.error_handler.error(
+                &expr.paren,
+                LoxErrorsTypes::Runtime(format!(
+                    "Missing argument '{}' in call to '{}' at",
+                    params[missing].lexeme, name
+                )),
+            ));
+        }
+        Ok(slots)
+    }
+
+    // Spread elements are expanded in place.
+    fn evaluate_elements(&self, exprs: &[Rc<Expr>]) -> Result<Vec<Literal>, LoxResult> {
+        let mut values: Vec<Literal> = Vec::new();
+        for expr in exprs.iter() {
+            let spread = match &**expr {
+                Expr::Spread(spread) => spread,
+                _ => {
+                    values.push(self.evaluate(expr.clone())?);
+                    continue;
+                }
+            };
+            match self.evaluate(spread.expr.clone())? {
+                Literal::Array(arr) => values.extend(arr.array.borrow().iter().map(|v| v.dup())),
+                val => {
+                    return Err(self.error_handler.error(
+                        &spread.token,
+                        LoxErrorsTypes::Type(format!(
+                            "Expected 'Array' to spread but got '{}' for",
+                            val.get_typename()
+                        )),
+                    ))
+                }
+            }
+        }
+        Ok(values)
+    }
+
+    fn no_keywords(&self, name: &str, expr: &CallExpr) -> Result<(), LoxResult> {
+        match expr.keywords.first() {
+            Some(keyword) => Err(self.error_handler.error(
+                keyword,
+                LoxErrorsTypes::Runtime(format!(
+                    "Cannot pass keyword arguments to '{}', found",
+                    name
+                )),
+            )),
+            None => Ok(()),
+        }
+    }
+
     pub fn execute_block(
         &self,
         stmts: &[Rc<Stmt>],
         enclosing: Environment,
     ) -> Result<(), LoxResult> {
-        let prev = self.environment.replace(Rc::new(RefCell::new(enclosing)));
+        self.execute_block_in(stmts, Rc::new(RefCell::new(enclosing)))
+    }
+
+    pub fn execute_block_in(
+        &self,
+        stmts: &[Rc<Stmt>],
+        enclosing: Rc<RefCell<Environment>>,
+    ) -> Result<(), LoxResult> {
+        let prev = self.environment.replace(enclosing);
         for stmt in stmts {
             if let Err(val) = self.execute(stmt.clone()) {
                 match val {
@@ -437,6 +1279,13 @@ impl VisitorExpr<Literal> for Interpreter {
         _: u16,
     ) -> Result<Literal, LoxResult> {
         let right = self.evaluate(expr.right.clone())?;
+        if let Literal::Instance(instance) = &right {
+            if expr.operator.token_type == TokenType::Minus {
+                if let Some(val) = self.call_operator(instance, "__neg__", &expr.operator, Vec::new())? {
+                    return Ok(val);
+                }
+            }
+        }
 
         self.check_num_unary(&expr.operator, &right)?;
         match expr.operator.token_type {
@@ -456,6 +1305,16 @@ impl VisitorExpr<Literal> for Interpreter {
     ) -> Result<Literal, LoxResult> {
         let left = self.evaluate(expr.left.clone())?;
         let right = self.evaluate(expr.right.clone())?;
+        if let Literal::Instance(instance) = &left {
+            if let Some(val) = self.binary_overload(instance, &expr.operator, right.dup())? {
+                return Ok(val);
+            }
+        }
+        let (left, right) = if expr.operator.token_type == TokenType::Plus {
+            self.coerce_concat(left, right)?
+        } else {
+            (left, right)
+        };
 
         self.check_num_binary(&expr.operator, &left, &right)?;
         match expr.operator.token_type {
@@ -466,8 +1325,8 @@ impl VisitorExpr<Literal> for Interpreter {
             TokenType::Modulus => Ok(Literal::Number(Rc::new(LoxNumber::new(
                 left.unwrap_number() % right.unwrap_number(),
             )))),
-            TokenType::BangEqual => Ok(Literal::Bool(!self.is_equal(left, right))),
-            TokenType::Equals => Ok(Literal::Bool(self.is_equal(left, right))),
+            TokenType::BangEqual => Ok(Literal::Bool(!self.is_equal(&left, &right)?)),
+            TokenType::Equals => Ok(Literal::Bool(self.is_equal(&left, &right)?)),
             TokenType::Greater
             | TokenType::Less
             | TokenType::GreaterEqual
@@ -539,7 +1398,11 @@ impl VisitorExpr<Literal> for Interpreter {
     ) -> Result<Literal, LoxResult> {
         let left = self.evaluate(expr.left.clone())?;
 
-        if expr.operator.token_type == TokenType::Or {
+        if expr.operator.token_type == TokenType::QuestionQuestion {
+            if !matches!(left, Literal::None | Literal::LiteralNone) {
+                return Ok(left);
+            }
+        } else if expr.operator.token_type == TokenType::Or {
             if self.is_truthy(&left) {
                 return Ok(left);
             }
@@ -558,7 +1421,20 @@ impl VisitorExpr<Literal> for Interpreter {
     ) -> Result<Literal, LoxResult> {
         let value = self.evaluate(expr.value.clone())?;
         let current_val = self.environment.borrow().borrow().get(&expr.name)?;
-        self.is_single_expr.replace(false);
+        if let Literal::Instance(instance) = &current_val {
+            if let Some(v) = self.binary_overload(instance, &expr.operator, value.dup())? {
+                self.environment
+                    .borrow_mut()
+                    .borrow_mut()
+                    .mutate(&expr.name, v.dup())?;
+                return Ok(v);
+            }
+        }
+        let (current_val, value) = if expr.operator.token_type == TokenType::PlusEqual {
+            self.coerce_concat(current_val, value)?
+        } else {
+            (current_val, value)
+        };
         self.check_compound_arithmetic(&expr.operator, &current_val, &value)?;
         match expr.operator.token_type {
             TokenType::PlusEqual => {
@@ -626,12 +1502,7 @@ impl VisitorExpr<Literal> for Interpreter {
 
     fn visit_call_expr(&self, _: Rc<Expr>, expr: &CallExpr, _: u16) -> Result<Literal, LoxResult> {
         let callee = self.evaluate(expr.callee.clone())?;
-
-        let mut args: Vec<Literal> = Vec::new();
-
-        for arg in expr.args.iter() {
-            args.push(self.evaluate(arg.clone())?);
-        }
+        let args = self.evaluate_elements(&expr.args)?;
 
         match callee {
             Literal::Func(func) => {
@@ -640,21 +1511,25 @@ impl VisitorExpr<Literal> for Interpreter {
                 } else {
                     "lambda"
                 };
-                if args.len() != func.arity() {
+                let args = self.bind_arguments(&func, name, expr, args)?;
+                self.catch_message(&expr.paren, func.call_with(Some(self), args))
+            }
+            Literal::Class(class) => {
+                if class.is_abstract() {
                     return Err(self.error_handler.error(
                         &expr.paren,
                         LoxErrorsTypes::Runtime(format!(
-                            "Expected {} arguments but got {} for '{}'",
-                            func.arity(),
-                            args.len(),
-                            name
+                            "Cannot instantiate abstract class '{}' for",
+                            class.name
                         )),
                     ));
                 }
-                self.catch_message(&expr.paren, func.call(Some(self), args))
-            }
-            Literal::Class(class) => {
-                if args.len() != class.arity() {
+                if let Some(init) = class.initializer() {
+                    let args = self.bind_arguments(&init, &class.name, expr, args)?;
+                    return self.catch_message(&expr.paren, class.call_with(Some(self), args));
+                }
+                self.no_keywords(&class.name, expr)?;
+                if !class.is_variadic() && args.len() != class.arity() {
                     return Err(self.error_handler.error(
                         &expr.paren,
                         LoxErrorsTypes::Runtime(format!(
@@ -667,7 +1542,14 @@ impl VisitorExpr<Literal> for Interpreter {
                 }
                 self.catch_message(&expr.paren, class.call(Some(self), args))
             }
+            Literal::Native(func) if func.native.accepts_keywords() => {
+                let mut args = args;
+                let named = args.split_off(args.len() - expr.keywords.len());
+                let keywords = expr.keywords.iter().map(|k| k.lexeme.to_string()).zip(named).collect();
+                self.catch_message(&expr.paren, func.native.call_keywords(Some(self), args, keywords))
+            }
             Literal::Native(func) => {
+                self.no_keywords(&func.name, expr)?;
                 if func.check_arity && args.len() != func.native.arity() {
                     return Err(self.error_handler.error(
                         &expr.paren,
@@ -698,18 +1580,89 @@ impl VisitorExpr<Literal> for Interpreter {
         Ok(Literal::Func(Rc::new(function)))
     }
 
+    fn visit_match_expr(
+        &self,
+        _: Rc<Expr>,
+        expr: &MatchExpr,
+        _: u16,
+    ) -> Result<Literal, LoxResult> {
+        let value = self.evaluate(expr.value.clone())?;
+        for arm in expr.arms.iter() {
+            let mut bindings = Vec::new();
+            if !self.match_pattern(&arm.pattern, &value, &mut bindings)? {
+                continue;
+            }
+
+            let mut env = Environment::new_enclosing(self.environment.borrow().clone());
+            for (name, val) in bindings {
+                env.define(&name, val)?;
+            }
+            let prev = self.environment.replace(Rc::new(RefCell::new(env)));
+            let result = self.execute_arm(arm);
+            self.environment.replace(prev);
+
+            if let Some(val) = result? {
+                return Ok(val);
+            }
+        }
+
+        Err(self.error_handler.error(
+            &expr.keyword,
+            LoxErrorsTypes::Runtime(format!("No arm matched value '{}' in", value.repr())),
+        ))
+    }
+
     fn visit_array_expr(
         &self,
         _: Rc<Expr>,
         expr: &ArrayExpr,
         _: u16,
     ) -> Result<Literal, LoxResult> {
-        let mut arr = Vec::new();
-        for val in &expr.arr {
-            arr.push(self.evaluate(val.clone())?);
+        let arr = self.evaluate_elements(&expr.arr)?;
+        Ok(Literal::Array(Rc::new(LoxArray::new(arr))))
+    }
+
+    fn visit_optional_expr(
+        &self,
+        _: Rc<Expr>,
+        expr: &OptionalExpr,
+        _: u16,
+    ) -> Result<Literal, LoxResult> {
+        match self.evaluate(expr.object.clone())? {
+            Literal::None | Literal::LiteralNone => Err(LoxResult::ShortCircuit),
+            val => Ok(val),
         }
+    }
 
-        Ok(Literal::Array(Rc::new(LoxArray::new(arr))))
+    fn visit_chain_expr(&self, _: Rc<Expr>, expr: &ChainExpr, _: u16) -> Result<Literal, LoxResult> {
+        match self.evaluate(expr.expr.clone()) {
+            Err(LoxResult::ShortCircuit) => Ok(Literal::None),
+            result => result,
+        }
+    }
+
+    fn visit_spread_expr(
+        &self,
+        _: Rc<Expr>,
+        expr: &SpreadExpr,
+        _: u16,
+    ) -> Result<Literal, LoxResult> {
+        Err(self.error_handler.error(
+            &expr.token,
+            LoxErrorsTypes::Syntax("Can only spread in calls and array literals, found".to_string()),
+        ))
+    }
+
+    fn visit_slice_expr(
+        &self,
+        _: Rc<Expr>,
+        expr: &SliceExpr,
+        _: u16,
+    ) -> Result<Literal, LoxResult> {
+        Err(self.error_handler.error(
+            &expr.bracket,
+            LoxErrorsTypes::Syntax("Can only slice inside an index, found".to_string()),
+        ))
     }
 
     fn visit_index_expr(
@@ -719,7 +1672,15 @@ impl VisitorExpr<Literal> for Interpreter {
         _: u16,
     ) -> Result<Literal, LoxResult> {
         let literal = self.evaluate(expr.identifier.clone())?;
+        if let Expr::Slice(slice) = &*expr.index {
+            return self.slice(&literal, slice);
+        }
         let index = self.evaluate(expr.index.clone())?;
+        if let Literal::Instance(instance) = &literal {
+            if let Some(val) = self.call_operator(instance, "__index__", &expr.bracket, vec![index.dup()])? {
+                return Ok(val);
+            }
+        }
         if let Literal::Array(arr) = literal {
             let num = self.check_index(&expr.bracket, &index)?;
             let len = arr.array.borrow().len() as isize;
@@ -736,14 +1697,15 @@ impl VisitorExpr<Literal> for Interpreter {
                     LoxErrorsTypes::Runtime("Can only index arrays with numbers".to_string()),
                 ));
             }
+            // Strings are indexed by character, not by byte
             let num = self.check_index(&expr.bracket, &index)?;
-            let len = str.string.borrow().len() as isize;
-            let string = *str
+            let len = str.string.borrow().chars().count() as isize;
+            let string = str
                 .string
-                .borrow_mut()
-                .as_bytes()
-                .get(self.check_index_bounds(&expr.bracket, num, len)?)
-                .unwrap() as char;
+                .borrow()
+                .chars()
+                .nth(self.check_index_bounds(&expr.bracket, num, len)?)
+                .unwrap();
             return Ok(Literal::Str(Rc::new(LoxString::new(string.to_string()))));
         }
         Err(self.error_handler.error(
@@ -752,11 +1714,29 @@ impl VisitorExpr<Literal> for Interpreter {
         ))
     }
 
-    fn visit_get_expr(&self, _: Rc<Expr>, expr: &GetExpr, _: u16) -> Result<Literal, LoxResult> {
+    fn visit_get_expr(
+        &self,
+        wrapper: Rc<Expr>,
+        expr: &GetExpr,
+        _: u16,
+    ) -> Result<Literal, LoxResult> {
         let object = self.evaluate(expr.object.clone())?;
         match object {
-            Literal::Instance(i) => return Ok(i.get(&expr.name, &i)?),
-            Literal::Class(c) => return Ok(c.get(&expr.name, &c)?),
+            Literal::Instance(i) => {
+                let private_access = self.private_access(&wrapper, &i);
+                if let Some(getter) = i.getter(&expr.name, &i, private_access)? {
+                    return getter.call(Some(self), Vec::new());
+                }
+                i.get(&expr.name, &i, private_access)
+            }
+            Literal::Class(c) => {
+                if let Some(getter) = c.getter(&expr.name, &c)? {
+                    return getter.call(Some(self), Vec::new());
+                }
+                c.get(&expr.name, &c)
+            }
+            Literal::Enum(e) => e.get(&expr.name, &e),
+            Literal::Variant(v) => v.get(&expr.name),
             Literal::Array(a) => return Ok(a.get(&expr.name)?),
             Literal::Str(s) => return Ok(s.get(&expr.name)?),
             Literal::Number(n) => return Ok(n.get(&expr.name)?),
@@ -767,20 +1747,36 @@ impl VisitorExpr<Literal> for Interpreter {
         }
     }
 
-    fn visit_set_expr(&self, _: Rc<Expr>, expr: &SetExpr, _: u16) -> Result<Literal, LoxResult> {
+    fn visit_set_expr(
+        &self,
+        wrapper: Rc<Expr>,
+        expr: &SetExpr,
+        _: u16,
+    ) -> Result<Literal, LoxResult> {
         let obj = self.evaluate(expr.object.clone())?;
 
         match obj {
             Literal::Instance(i) => {
-                let prev = *i.this.borrow();
-                let current = i.get(&expr.name, &i)?;
+                let private_access = self.private_access(&wrapper, &i);
+                if let Some(setter) = i.setter(&expr.name, &i, private_access)? {
+                    let val = self.accessor_value(expr, || {
+                        i.getter(&expr.name, &i, private_access)
+                    })?;
+                    setter.call(Some(self), vec![val.dup()])?;
+                    return Ok(val);
+                }
+                let current = i.get(&expr.name, &i, private_access)?;
                 let new = self.evaluate(expr.value.clone())?;
                 let val = self.operate_compound_set(&expr.operator, current.dup(), new.dup())?;
-                i.this.replace(prev);
-                i.set(&expr.name, val.dup())?;
+                i.set(&expr.name, val.dup(), private_access)?;
                 return Ok(val);
             }
             Literal::Class(c) => {
+                if let Some(setter) = c.setter(&expr.name, &c)? {
+                    let val = self.accessor_value(expr, || c.getter(&expr.name, &c))?;
+                    setter.call(Some(self), vec![val.dup()])?;
+                    return Ok(val);
+                }
                 let current = c.get(&expr.name, &c)?;
                 let new = self.evaluate(expr.value.clone())?;
                 let val = self.operate_compound_set(&expr.operator, current.dup(), new.dup())?;
@@ -821,6 +1817,27 @@ impl VisitorExpr<Literal> for Interpreter {
                         }
                         Ok(var)
                     }
+                    Literal::Instance(instance) => {
+                        let one = Literal::Number(Rc::new(LoxNumber::new(1_f64)));
+                        let name = Interpreter::operator_method(expr.operator.token_type)
+                            .expect("update operators are '++' and '--'");
+                        match self.call_operator(instance, name, &expr.operator, vec![one])? {
+                            Some(updated) => {
+                                self.environment
+                                    .borrow()
+                                    .borrow_mut()
+                                    .mutate(&v.name, updated.dup())?;
+                                if expr.prefix {
+                                    return Ok(updated);
+                                }
+                                Ok(var.dup())
+                            }
+                            None => Err(self.error_handler.error(
+                                &expr.operator,
+                                LoxErrorsTypes::Type("Invalid type for".to_string()),
+                            )),
+                        }
+                    }
                     _ => Err(self.error_handler.error(
                         &expr.operator,
                         LoxErrorsTypes::Type("Invalid type for".to_string()),
@@ -843,7 +1860,6 @@ impl VisitorExpr<Literal> for Interpreter {
         let obj = self.look_up_variable(&expr.keyword, &wrapper.clone())?;
         match obj {
             Literal::Instance(inst) => {
-                *inst.this.borrow_mut() = true;
                 return Ok(Literal::Instance(inst));
             }
             Literal::Class(_) => {
@@ -863,9 +1879,27 @@ impl VisitorExpr<Literal> for Interpreter {
     ) -> Result<Literal, LoxResult> {
         let mut literal = self.evaluate(expr.identifier.clone())?;
         let value = self.evaluate(expr.value.clone())?;
+        if let Expr::Slice(slice) = &*expr.index {
+            self.assign_slice(&literal, slice, &value)?;
+            self.env_mutate_at(wrapper, &expr.name, literal.dup())?;
+            return Ok(literal);
+        }
         let index = self.evaluate(expr.index.clone())?;
 
         match &mut literal {
+            Literal::Instance(instance) => {
+                let args = vec![index.dup(), value.dup()];
+                match self.call_operator(instance, "__setindex__", &expr.bracket, args)? {
+                    Some(_) => Ok(value),
+                    None => Err(self.error_handler.error(
+                        &expr.bracket,
+                        LoxErrorsTypes::Type(format!(
+                            "'{}' does not support item assignment",
+                            instance.class_name()
+                        )),
+                    )),
+                }
+            }
             Literal::Array(arr) => {
                 let num = self.check_index(&expr.bracket, &index)?;
                 let len = arr.array.borrow().len() as isize;
@@ -893,9 +1927,83 @@ impl VisitorStmt<()> for Interpreter {
         stmt: &ExpressionStmt,
         _: u16,
     ) -> Result<(), LoxResult> {
-        let val = self.evaluate(stmt.expr.clone())?;
-        if self.is_repl && *self.is_single_expr.borrow() {
-            val.print_value();
+        self.evaluate(stmt.expr.clone())?;
+        Ok(())
+    }
+
+    fn visit_destructure_stmt(
+        &self,
+        _: Rc<Stmt>,
+        stmt: &DestructureStmt,
+        _: u16,
+    ) -> Result<(), LoxResult> {
+        let value = self.evaluate(stmt.initializer.clone())?;
+        let mut bindings: Vec<(Token, Literal)> = Vec::new();
+        match &stmt.target {
+            Destructure::Array {
+                bracket,
+                names,
+                rest,
+            } => {
+                let arr = match &value {
+                    Literal::Array(arr) => arr.array.borrow().clone(),
+                    _ => {
+                        return Err(self.error_handler.error(
+                            bracket,
+                            LoxErrorsTypes::Runtime(format!(
+                                "Cannot destructure '{}' as an array at",
+                                value.get_typename()
+                            )),
+                        ))
+                    }
+                };
+                if arr.len() < names.len() || (rest.is_none() && arr.len() != names.len()) {
+                    let expected = match rest {
+                        Some(_) => format!("at least {}", names.len()),
+                        None => names.len().to_string(),
+                    };
+                    return Err(self.error_handler.error(
+                        bracket,
+                        LoxErrorsTypes::Runtime(format!(
+                            "Expected {} elements to destructure but got {} at",
+                            expected,
+                            arr.len()
+                        )),
+                    ));
+                }
+                for (name, val) in names.iter().zip(arr.iter()) {
+                    bindings.push((name.dup(), val.dup()));
+                }
+                if let Some(rest) = rest {
+                    let rest_arr = LoxArray::new(arr[names.len()..].to_vec());
+                    bindings.push((rest.dup(), Literal::Array(Rc::new(rest_arr))));
+                }
+            }
+            Destructure::Object { brace, names } => {
+                for name in names.iter() {
+                    let val = match &value {
+                        Literal::Instance(i) => match i.getter(name, i, false)? {
+                            Some(getter) => getter.call(Some(self), Vec::new())?,
+                            None => i.get(name, i, false)?,
+                        },
+                        Literal::Variant(v) => v.get(name)?,
+                        _ => {
+                            return Err(self.error_handler.error(
+                                brace,
+                                LoxErrorsTypes::Runtime(format!(
+                                    "Cannot destructure '{}' as an object at",
+                                    value.get_typename()
+                                )),
+                            ))
+                        }
+                    };
+                    bindings.push((name.dup(), val));
+                }
+            }
+        }
+
+        for (name, val) in bindings {
+            self.environment.borrow_mut().borrow_mut().define(&name, val)?;
         }
         Ok(())
     }
@@ -907,6 +2015,13 @@ impl VisitorStmt<()> for Interpreter {
             Literal::LiteralNone
         };
 
+        if stmt.is_const {
+            return self
+                .environment
+                .borrow_mut()
+                .borrow_mut()
+                .define_const(&stmt.name, val);
+        }
         self.environment
             .borrow_mut()
             .borrow_mut()
@@ -1025,7 +2140,12 @@ impl VisitorStmt<()> for Interpreter {
         Ok(())
     }
 
-    fn visit_class_stmt(&self, _: Rc<Stmt>, stmt: &ClassStmt, _: u16) -> Result<(), LoxResult> {
+    fn visit_class_stmt(
+        &self,
+        wrapper: Rc<Stmt>,
+        stmt: &ClassStmt,
+        _: u16,
+    ) -> Result<(), LoxResult> {
         self.environment
             .borrow()
             .borrow_mut()
@@ -1033,16 +2153,24 @@ impl VisitorStmt<()> for Interpreter {
         let mut methods: HashMap<String, Literal> = HashMap::new();
         let mut static_fields: HashMap<String, Literal> = HashMap::new();
         let mut other_fields: HashMap<String, InstanceField> = HashMap::new();
+        let mut constants: Vec<String> = Vec::new();
+        let mut initializers: Vec<(String, Rc<Expr>)> = Vec::new();
 
         for field in stmt.fields.iter() {
             match &**field {
                 Stmt::Field(f) => {
-                    let value = if let Some(val) = &f.initializer {
-                        self.evaluate(val.clone())?
-                    } else {
-                        Literal::None
+                    let value = match &f.initializer {
+                        Some(val) if f.is_static => self.evaluate(val.clone())?,
+                        Some(val) => {
+                            initializers.push((f.name.lexeme.to_string(), val.clone()));
+                            Literal::None
+                        }
+                        None => Literal::None,
                     };
                     if f.is_static {
+                        if f.is_const {
+                            constants.push(f.name.lexeme.to_string());
+                        }
                         static_fields.insert(f.name.lexeme.to_string(), value.dup());
                     } else {
                         other_fields.insert(
@@ -1050,6 +2178,7 @@ impl VisitorStmt<()> for Interpreter {
                             InstanceField {
                                 value: value.dup(),
                                 is_public: f.is_pub,
+                                is_const: f.is_const,
                             },
                         );
                     }
@@ -1069,6 +2198,7 @@ impl VisitorStmt<()> for Interpreter {
                     );
                     methods.insert(f.name.lexeme.to_string(), Literal::Func(Rc::new(func)));
                 }
+                Stmt::Signature(_) => {}
                 _ => panic!("unexpected statement {m:?}"),
             }
         }
@@ -1077,7 +2207,11 @@ impl VisitorStmt<()> for Interpreter {
             methods,
             static_fields,
             other_fields,
-        );
+        )
+        .with_accessors(self.accessors(&stmt.getters), self.accessors(&stmt.setters))
+        .with_initializers(initializers, self.environment.borrow().clone())
+        .with_constants(constants)
+        .declared_by(wrapper);
         self.environment
             .borrow()
             .borrow_mut()
@@ -1088,4 +2222,496 @@ impl VisitorStmt<()> for Interpreter {
     fn visit_field_stmt(&self, _: Rc<Stmt>, _: &FieldStmt, _: u16) -> Result<(), LoxResult> {
         Ok(())
     }
+
+    fn visit_signature_stmt(&self, _: Rc<Stmt>, _: &SignatureStmt, _: u16) -> Result<(), LoxResult> {
+        Ok(())
+    }
+
+    fn visit_enum_stmt(&self, _: Rc<Stmt>, stmt: &EnumStmt, _: u16) -> Result<(), LoxResult> {
+        let mut variants: Vec<EnumVariant> = Vec::new();
+        for variant in stmt.variants.iter() {
+            match &**variant {
+                Stmt::Variant(v) => variants.push(EnumVariant {
+                    name: v.name.lexeme.to_string(),
+                    fields: v
+                        .fields
+                        .as_ref()
+                        .map(|fields| fields.iter().map(|f| f.lexeme.to_string()).collect()),
+                }),
+                _ => panic!("unexpected statement {variant:?}"),
+            }
+        }
+        let val = Literal::Enum(Rc::new(LoxEnum::new(&stmt.name.lexeme, variants)));
+        self.environment
+            .borrow_mut()
+            .borrow_mut()
+            .define(&stmt.name, val)?;
+        Ok(())
+    }
+
+    fn visit_variant_stmt(&self, _: Rc<Stmt>, _: &VariantStmt, _: u16) -> Result<(), LoxResult> {
+        Ok(())
+    }
+
+    fn visit_interface_stmt(&self, _: Rc<Stmt>, _: &InterfaceStmt, _: u16) -> Result<(), LoxResult> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::LoxErrorsTypes,
+        runtime::tests::{eval, global, run, run_err},
+    };
+
+    const POINT: &str = "class P {
+        public x;
+        init(x) { this.x = x; }
+        public toString() { return \"P(\" + Str(this.x) + \")\"; }
+    }";
+
+    #[test]
+    fn to_string_in_print() {
+        let src = format!("{POINT} let p = P(1); let nested = [P(2), \"a\"]; print(p, nested);");
+        let interpreter = run(&src).unwrap();
+        assert_eq!(interpreter.stringify(&global(&interpreter, "p")).unwrap(), "P(1)");
+        let nested = interpreter.stringify(&global(&interpreter, "nested")).unwrap();
+        assert_eq!(nested, "[P(2), \"a\"]");
+    }
+
+    #[test]
+    fn to_string_in_str_and_concat() {
+        let src = format!("{POINT} let r = [Str(P(1)), \"at \" + P(2), P(3) + \"!\"];");
+        assert_eq!(eval(&src, "r"), "[\"P(1)\", \"at P(2)\", \"P(3)!\"]");
+        let src = format!("{POINT} let s = \"p: \"; s += P(4);");
+        assert_eq!(eval(&src, "s"), "\"p: P(4)\"");
+    }
+
+    #[test]
+    fn without_to_string() {
+        let src = "class Q { public a = 1; init() {} } let r = Str(Q());";
+        assert_eq!(eval(src, "r"), "\"Q { a: 1 }\"");
+    }
+
+    const VEC: &str = "class V {
+        public x;
+        init(x) { this.x = x; }
+        public __eq__(other) { return typeof(other) == typeof(this) and this.x == other.x; }
+        public __hash__() { return this.x; }
+    }";
+
+    #[test]
+    fn deep_array_equality() {
+        let src = "let a = [1, [2, [\"b\"]], none];
+            let cyclic = [1]; cyclic.push(cyclic);
+            let other = [1]; other.push(other);
+            let r = [a == [1, [2, [\"b\"]], none], a == [1, [2, [\"c\"]], none], [1] == [1, 2],
+                [1] != [\"1\"], cyclic == other];";
+        assert_eq!(eval(src, "r"), "[true, false, false, true, true]");
+    }
+
+    #[test]
+    fn instance_equality() {
+        let src = format!("{VEC} class Plain {{ init() {{}} }}
+            let p = Plain();
+            let r = [V(1) == V(1), V(1) != V(2), p == p, p == Plain(), V(1) == 1, 1 == V(1),
+                p == V(1), V(1) == p];");
+        assert_eq!(eval(&src, "r"), "[true, true, true, false, false, false, false, false]");
+    }
+
+    #[test]
+    fn equality_is_symmetric() {
+        let src = "class One { init() {} public __eq__(other) { return other == 1; } }
+            let r = [One() == 1, 1 == One(), \"a\" == One()];";
+        assert_eq!(eval(src, "r"), "[true, true, false]");
+    }
+
+    #[test]
+    fn equal_values_hash_equal() {
+        let src = format!("{VEC} class Plain {{ init() {{}} }}
+            let p = Plain();
+            let r = [hash([1, [2, \"a\"]]) == hash([1, [2, \"a\"]]), hash(V(3)) == hash(V(3)),
+                hash(0) == hash(-0), hash(p) == hash(p), hash(\"a\") == hash(\"a\")];");
+        assert_eq!(eval(&src, "r"), "[true, true, true, true, true]");
+    }
+
+    #[test]
+    fn instance_hash_normalises_zero() {
+        let src = "class Z { public h; init(h) { this.h = h; } public __hash__() { return this.h; } }
+            let r = [hash(Z(0)) == hash(Z(-0)), hash(Z(-0)) == hash(0), hash(Z(1)) == hash(Z(2))];";
+        assert_eq!(eval(src, "r"), "[true, true, false]");
+    }
+
+    #[test]
+    fn unhashable_instances() {
+        let src = "class E { init() {} public __eq__(other) { return true; } } hash(E());";
+        assert!(matches!(run_err(src), LoxErrorsTypes::Runtime(_)));
+        let src = "class H { init() {} public __hash__() { return \"h\"; } } hash(H());";
+        assert!(matches!(run_err(src), LoxErrorsTypes::Runtime(_)));
+    }
+
+    #[test]
+    fn array_members_use_equality() {
+        let src = format!("{VEC} let arr = [V(1), [2, 3], V(4)];
+            let r = [arr.contains(V(4)), arr.contains([2, 3]), arr.contains(V(5)),
+                arr.index_of(V(4)), arr.index_of([2, 3]), arr.index_of(2)];");
+        assert_eq!(eval(&src, "r"), "[true, true, false, 2, 1, -1]");
+    }
+
+    const COUNTER: &str = "class Counter {
+        public count = 0;
+        public step;
+        init(step) { this.step = step; }
+        public increment() { this.count += this.step; return this.count; }
+        public adder() { return lm(n) => this.count + n; }
+        public nested() { return lm() => lm() => this.step; }
+    }";
+
+    #[test]
+    fn bound_method_keeps_this() {
+        let src = format!("{COUNTER} let c = Counter(2); let inc = c.increment; inc(); inc();
+            let other = Counter(10);
+            fn call(f) {{ return f(); }}
+            let r = [c.count, call(inc), other.count];");
+        assert_eq!(eval(&src, "r"), "[4, 6, 0]");
+    }
+
+    #[test]
+    fn lambda_captures_this() {
+        let src = format!("{COUNTER} let c = Counter(3); let add = c.adder(); c.increment();
+            let d = Counter(100); let r = [add(10), d.adder()(1), c.nested()()()];");
+        assert_eq!(eval(&src, "r"), "[13, 1, 3]");
+    }
+
+    #[test]
+    fn lambda_field_called_later() {
+        let src = "class Box {
+                public f;
+                public v = 1;
+                init() {}
+                public set() { this.f = lm() => this.v; }
+            }
+            let a = Box(); a.set();
+            let b = Box(); b.v = 2; b.f = a.f;
+            let r = [a.f(), b.f()];";
+        assert_eq!(eval(src, "r"), "[1, 1]");
+    }
+
+    #[test]
+    fn slice_bounds_can_change_the_array() {
+        let src = "let a = [1, 2]; fn grow() { a.push(3); return 3; }
+            let r = a[0:grow()];";
+        assert_eq!(eval(src, "r"), "[1, 2, 3]");
+        let src = "let a = [1, 2, 3, 4]; fn shrink() { a.pop(); a.pop(); return 4; }
+            a[1:shrink()] = [9]; let r = a;";
+        assert_eq!(eval(src, "r"), "[1, 9]");
+    }
+
+    const MONEY: &str = "class M {
+        public c;
+        init(c) { this.c = c; }
+        public __add__(o) { return M(this.c + (typeof(o) == typeof(this) ? o.c : o)); }
+        public __sub__(o) { return M(this.c - o.c); }
+        public __mul__(k) { return M(this.c * k); }
+        public __div__(k) { return M(this.c / k); }
+        public __mod__(k) { return M(this.c % k); }
+        public __neg__() { return M(-this.c); }
+        public __lt__(o) { return this.c < o.c; }
+        public __eq__(o) { return typeof(o) == typeof(this) and this.c == o.c; }
+        public __index__(i) { return this.c * i; }
+        public __setindex__(i, v) { this.c = i + v; }
+    }";
+
+    #[test]
+    fn arithmetic_overloads() {
+        let src = format!("{MONEY} let m = M(6);
+            let r = [(m + M(1)).c, (m - M(1)).c, (m * 2).c, (m / 4).c, (m % 4).c, (-m).c];");
+        assert_eq!(eval(&src, "r"), "[7, 5, 12, 1.5, 2, -6]");
+        let src = format!("{MONEY} let m = M(1); m += M(2); m *= 3; m++; let r = m.c;");
+        assert_eq!(eval(&src, "r"), "10");
+    }
+
+    #[test]
+    fn comparison_overloads() {
+        let src = format!("{MONEY} let m = M(6);
+            let r = [m < M(7), m < M(6), m <= M(6), m > M(5), m > M(6), m >= M(6), m >= M(7),
+                m == M(6), m != M(6)];");
+        assert_eq!(eval(&src, "r"), "[true, false, true, true, false, true, false, true, false]");
+    }
+
+    #[test]
+    fn index_overloads() {
+        let src = format!("{MONEY} let m = M(6); let a = m[3]; m[1] = 2; let r = [a, m.c];");
+        assert_eq!(eval(&src, "r"), "[18, 3]");
+    }
+
+    #[test]
+    fn missing_overloads() {
+        let plain = "class P { init() {} } let p = P();";
+        for (op, expected) in [
+            ("p + 1;", "Operands must be either numbers or strings for"),
+            ("p - 1;", "Operands must be numbers for"),
+            ("p < 1;", "Operands must be numbers for"),
+            ("1 < p;", "Operands must be numbers for"),
+            ("-p;", "Operand must be of type number"),
+        ] {
+            let src = format!("{plain} {op}");
+            assert_eq!(run_err(&src), LoxErrorsTypes::Type(expected.to_string()), "{op}");
+        }
+        let src = format!("{plain} p[0];");
+        assert!(matches!(run_err(&src), LoxErrorsTypes::Runtime(_)));
+    }
+
+    #[test]
+    fn overload_with_wrong_arity() {
+        let src = "class P { init() {} public __add__() { return 1; } } P() + 1;";
+        let expected = "Expected '__add__' to take 1 arguments but it takes 0 for";
+        assert_eq!(run_err(src), LoxErrorsTypes::Runtime(expected.to_string()));
+    }
+
+    const KIND: &str = "fn kind(v) {
+        return match (v) {
+            1 | 2 => \"small\",
+            3..10 => \"mid\",
+            10..=20 => \"big\",
+            [a, b] => a + b,
+            none => \"nothing\",
+            x => x,
+        };
+    }";
+
+    #[test]
+    fn match_patterns() {
+        let src = format!("{KIND} let r = [kind(1), kind(2), kind(3), kind(9), kind(10), kind(20),
+            kind(21), kind([1, 2]), kind([1, 2, 3]), kind(none), kind(\"s\")];");
+        let expected = concat!(
+            r#"["small", "small", "mid", "mid", "big", "big", 21, 3, [1, 2, 3], "#,
+            r#""nothing", "s"]"#
+        );
+        assert_eq!(eval(&src, "r"), expected);
+    }
+
+    #[test]
+    fn match_guards_and_variants() {
+        let src = "enum Shape { Circle(r), Rect(w, h), Empty }
+            fn area(s) {
+                return match (s) {
+                    Shape.Circle(r) if r > 1 => r * r,
+                    Shape.Circle(r) => -r,
+                    Shape.Rect(w, h) => w * h,
+                    _ => 0,
+                };
+            }
+            let r = [area(Shape.Circle(3)), area(Shape.Circle(1)), area(Shape.Rect(2, 5)),
+                area(Shape.Empty)];";
+        assert_eq!(eval(src, "r"), "[9, -1, 10, 0]");
+    }
+
+    #[test]
+    fn match_bindings_are_scoped_to_the_arm() {
+        let src = "let x = \"outer\"; let r = [match (1) { x if x > 5 => x, _ => x }, x];";
+        assert_eq!(eval(src, "r"), r#"["outer", "outer"]"#);
+    }
+
+    #[test]
+    fn match_without_matching_arm() {
+        let expected = "No arm matched value '7' in".to_string();
+        assert_eq!(run_err("match (7) { 1 => 1, 2..5 => 2 };"), LoxErrorsTypes::Runtime(expected));
+        let src = "let r = match (\"a\") { 1..3 => 1, _ => 2 };";
+        assert_eq!(eval(src, "r"), "2");
+    }
+
+    #[test]
+    fn destructuring() {
+        let src = "class P { public name; public age; init(n, a) { this.name = n; this.age = a; } }
+            let [a, b, ...rest] = [1, 2, 3, 4];
+            let [x, ...empty] = [5];
+            let { name, age } = P(\"x\", 3);
+            fn head([h, ...t]) { return [h, t]; }
+            let r = [a, b, rest, x, empty, name, age, head([6, 7, 8])];";
+        assert_eq!(eval(src, "r"), r#"[1, 2, [3, 4], 5, [], "x", 3, [6, [7, 8]]]"#);
+    }
+
+    #[test]
+    fn destructuring_errors() {
+        for (src, expected) in [
+            ("let [a, b] = [1];", "Expected 2 elements to destructure but got 1 at"),
+            ("let [a, ...b] = [];", "Expected at least 1 elements to destructure but got 0 at"),
+            ("let [a] = 1;", "Cannot destructure 'Number' as an array at"),
+            ("fn f([a]) { return a; } f(\"a\");", "Cannot destructure 'String' as an array at"),
+        ] {
+            assert_eq!(run_err(src), LoxErrorsTypes::Runtime(expected.to_string()), "{src}");
+        }
+        let src = "class P { private s = 1; init() {} } let { s } = P();";
+        let expected = LoxErrorsTypes::ReferenceError("Cannot get private property".to_string());
+        assert_eq!(run_err(src), expected);
+        let err = run_err("class P { init() {} } let { q } = P();");
+        assert!(matches!(err, LoxErrorsTypes::Runtime(_)));
+    }
+
+    #[test]
+    fn default_and_keyword_arguments() {
+        let src = "fn greet(name, greeting = \"Hello\", end = \"!\") {
+                return greeting + \" \" + name + end;
+            }
+            let r = [greet(\"A\"), greet(\"A\", \"Hi\"), greet(\"A\", end: \"?\"),
+                greet(greeting: \"Yo\", name: \"B\")];";
+        assert_eq!(eval(src, "r"), r#"["Hello A!", "Hi A!", "Hello A?", "Yo B!"]"#);
+        let src = "class C {
+                public v;
+                init(a, b = 2) { this.v = a * b; }
+                public m(x = 3) { return x; }
+            }
+            let l = lm(x = 4) => x;
+            let r = [C(1).v, C(1, b: 5).v, C(1).m(), l()];";
+        assert_eq!(eval(src, "r"), "[2, 5, 3, 4]");
+    }
+
+    #[test]
+    fn defaults_are_evaluated_per_call() {
+        let src = "let n = 0; fn next() { n += 1; return n; } fn g(a = next()) { return a; }
+            let r = [g(), g(), g(5), n];";
+        assert_eq!(eval(src, "r"), "[1, 2, 5, 2]");
+    }
+
+    #[test]
+    fn default_and_keyword_argument_errors() {
+        let g = "fn g(a, b = 1) { return a + b; }";
+        for (call, expected) in [
+            ("g();", "Expected 1 to 2 arguments but got 0 for 'g'"),
+            ("g(1, 2, 3);", "Expected 1 to 2 arguments but got 3 for 'g'"),
+            ("g(1, c: 2);", "No parameter in 'g' named"),
+            ("g(1, a: 2);", "Multiple values in call to 'g' for parameter"),
+        ] {
+            let err = run_err(&format!("{g} {call}"));
+            assert_eq!(err, LoxErrorsTypes::Runtime(expected.to_string()), "{call}");
+        }
+    }
+
+    #[test]
+    fn rest_and_spread() {
+        let src = "fn collect(first, ...rest) { return [first, rest]; } let arr = [1, 2, 3];
+            let r = [collect(...arr), collect(0), collect(0, ...arr, 9), [...arr, ...[4, 5], 6],
+                Math.max(...arr)];";
+        let expected = "[[1, [2, 3]], [0, []], [0, [1, 2, 3, 9]], [1, 2, 3, 4, 5, 6], 3]";
+        assert_eq!(eval(src, "r"), expected);
+        let src = "let arr = [1]; let copy = [...arr]; copy.push(2); let r = [arr, copy];";
+        assert_eq!(eval(src, "r"), "[[1], [1, 2]]");
+    }
+
+    #[test]
+    fn rest_and_spread_errors() {
+        let spread =
+            LoxErrorsTypes::Type("Expected 'Array' to spread but got 'Number' for".to_string());
+        assert_eq!(run_err("fn f(...a) { return a; } f(...1);"), spread);
+        assert_eq!(run_err("let x = [...1];"), spread);
+        let expected = "Expected 2 arguments but got 3 for 'f'".to_string();
+        let src = "fn f(a, b) { return a; } f(...[1, 2, 3]);";
+        assert_eq!(run_err(src), LoxErrorsTypes::Runtime(expected));
+        let expected = "Expected at least 1 arguments but got 0 for 'f'".to_string();
+        let src = "fn f(a, ...b) { return a; } f();";
+        assert_eq!(run_err(src), LoxErrorsTypes::Runtime(expected));
+    }
+
+    #[test]
+    fn constants() {
+        let src = "const MAX = 100; fn f() { const L = 2; return L * MAX; }
+            class C { public const N = \"a\"; static const V = 2; init() {} }
+            let r = [MAX, f(), C().N, C.V];";
+        assert_eq!(eval(src, "r"), r#"[100, 200, "a", 2]"#);
+    }
+
+    #[test]
+    fn assigning_constants() {
+        // Reported by the resolver, which doesn't stop at the first error
+        for src in ["MAX = 200;", "MAX += 1;", "MAX++;", "fn f() { MAX = 1; }"] {
+            assert!(matches!(run(&format!("const MAX = 100; {src}")), Err(None)), "{src}");
+        }
+        assert!(matches!(run("fn f() { const L = 2; L = 3; }"), Err(None)));
+        let src = "const X = 1; fn f() { let X = 2; X = 3; return X; } let r = [f(), X];";
+        assert_eq!(eval(src, "r"), "[3, 1]");
+
+        let field = LoxErrorsTypes::Type("Cannot assign to constant field".to_string());
+        let class = "class C { public const N = \"a\"; static const V = 2; init() {} }";
+        assert_eq!(run_err(&format!("{class} C().N = \"b\";")), field);
+        assert_eq!(run_err(&format!("{class} C.V = 3;")), field);
+    }
+
+    const USER: &str = "class U {
+        public name = \"n\";
+        public friends = [\"a\"];
+        public address = none;
+        init() {}
+        public greet(x) { return \"hi\"; }
+    }
+    let calls = 0;
+    fn f() { calls += 1; return \"d\"; }";
+
+    #[test]
+    fn optional_chaining() {
+        let src = format!("{USER} let u = none;
+            let r = [u?.name, u?.greet(f()), u?.friends?.[0], u?.address.street, calls];");
+        assert_eq!(eval(&src, "r"), "[none, none, none, none, 0]");
+        let src = format!("{USER} let u = U();
+            let r = [u?.name, u?.greet(1), u?.friends?.[0], u?.address?.street];");
+        assert_eq!(eval(&src, "r"), r#"["n", "hi", "a", none]"#);
+    }
+
+    #[test]
+    fn optional_chaining_errors() {
+        // Only the value before `?.` may be none
+        let src = format!("{USER} U()?.address.street;");
+        let expected = LoxErrorsTypes::Runtime("Only instances have properties".to_string());
+        assert_eq!(run_err(&src), expected);
+        assert!(matches!(run_err("let u = 1; u?.name;"), LoxErrorsTypes::Runtime(_)));
+    }
+
+    #[test]
+    fn none_coalescing() {
+        let src = format!("{USER}
+            let r = [none ?? f(), 0 ?? f(), false ?? f(), \"\" ?? f(), calls];");
+        assert_eq!(eval(&src, "r"), r#"["d", 0, false, "", 1]"#);
+    }
+
+    #[test]
+    fn none_coalescing_assignment() {
+        let src = format!("{USER} let x = none; x ??= f(); let y = 5; y ??= f();
+            let u = U(); u.address ??= 1; u.address ??= 2;
+            let a = [none, 3]; a[0] ??= 4; a[1] ??= 5;
+            let r = [x, y, u.address, a, calls];");
+        assert_eq!(eval(&src, "r"), r#"["d", 5, 1, [4, 3], 1]"#);
+        // Still an assignment, even when the value is not none
+        assert!(matches!(run("const K = 1; K ??= 2;"), Err(None)));
+    }
+
+    #[test]
+    fn arrow_lambdas() {
+        let src = "let add = lm(a, b) => a + b;
+            let k = 10; let add_k = lm(x) => x + k; k = 20;
+            let curried = lm(a) => lm(b) => a * b;
+            let r = [add(1, 2), add_k(1), curried(3)(4), [1, 2].map(lm(x) => x * 2),
+                (lm() => none)()];";
+        assert_eq!(eval(src, "r"), "[3, 21, 12, [2, 4], none]");
+    }
+
+    #[test]
+    fn arrow_lambda_errors() {
+        let expected = "Expected 2 arguments but got 1 for 'lambda'".to_string();
+        let src = "let add = lm(a, b) => a + b; add(1);";
+        assert_eq!(run_err(src), LoxErrorsTypes::Runtime(expected));
+        assert!(matches!(run_err("let f = lm(a) => a.b; f(1);"), LoxErrorsTypes::Runtime(_)));
+        assert!(matches!(run_err("let f = lm() => return 1;"), LoxErrorsTypes::Syntax(_)));
+    }
+
+    #[test]
+    fn to_string_not_returning_string() {
+        let src = "class B { init() {} public toString() { return 1; } }";
+        let expected = LoxErrorsTypes::Type(
+            "Expected 'String' to be returned but got 'Number' from".to_string(),
+        );
+        assert_eq!(run_err(&format!("{src} print(B());")), expected);
+        assert_eq!(run_err(&format!("{src} Str(B());")), expected);
+        assert_eq!(run_err(&format!("{src} \"\" + B();")), expected);
+    }
 }
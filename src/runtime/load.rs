@@ -4,9 +4,18 @@ use crate::{
     error::LoxResult,
     lexer::{literal::Literal, token::Token, tokentype::TokenType},
     loxlib::{
-        array::array_class_members::ArrayMembers, clock::Clock, input::Input,
-        loxnatives::LoxNative, number::number_class_member::NumberMembers, print::Print,
-        string::string_class_member::StringMembers, typeofliteral::TypeOf,
+        array::array_class_members::ArrayMembers,
+        clock::Clock,
+        format::Format,
+        hash::HashOf,
+        input::Input,
+        loxnatives::LoxNative,
+        math::math_class_members::{MathMembers, CONSTANTS},
+        number::number_class_member::NumberMembers,
+        print::Print,
+        random::{prng::Prng, random_class_members::RandomMembers},
+        string::string_class_member::StringMembers,
+        typeofliteral::TypeOf,
     },
 };
 
@@ -15,7 +24,9 @@ use super::{environment::Environment, loxclass::LoxClass};
 pub fn load(env: Rc<RefCell<Environment>>) -> Result<(), LoxResult> {
     let array_members = ArrayMembers::new(Rc::new(RefCell::new(Vec::new())));
     let string_members = StringMembers::new(Rc::new(RefCell::new(String::new())));
-    let number_members = NumberMembers::new();
+    let number_members = NumberMembers::new(Rc::new(RefCell::new(0.0)));
+    let math_members = MathMembers::new();
+    let random_members = RandomMembers::new(Rc::new(RefCell::new(Prng::from_time())));
     let natives = [
         (
             Token::new(TokenType::DefFn, "clock".to_string(), None, 0),
@@ -33,30 +44,30 @@ pub fn load(env: Rc<RefCell<Environment>>) -> Result<(), LoxResult> {
             Token::new(TokenType::DefFn, "typeof".to_string(), None, 0),
             Literal::Native(Rc::new(LoxNative::new("typeof", Rc::new(TypeOf {}), true))),
         ),
+        (
+            Token::new(TokenType::DefFn, "hash".to_string(), None, 0),
+            Literal::Native(Rc::new(LoxNative::new("hash", Rc::new(HashOf {}), true))),
+        ),
+        (
+            Token::new(TokenType::DefFn, "format".to_string(), None, 0),
+            Literal::Native(Rc::new(LoxNative::new("format", Rc::new(Format {}), false))),
+        ),
         (
             Token::new(TokenType::Class, "Array".to_string(), None, 0),
-            Literal::Native(Rc::new(LoxNative::new(
+            Literal::Class(Rc::new(LoxClass::new(
                 "Array",
-                Rc::new(LoxClass::new(
-                    "Array",
-                    array_members.get_methods(),
-                    array_members.get_statics(),
-                    array_members.get_fields(),
-                )),
-                false,
+                array_members.get_methods(),
+                array_members.get_statics(),
+                array_members.get_fields(),
             ))),
         ),
         (
             Token::new(TokenType::Class, "Str".to_string(), None, 0),
-            Literal::Native(Rc::new(LoxNative::new(
+            Literal::Class(Rc::new(LoxClass::new(
                 "Str",
-                Rc::new(LoxClass::new(
-                    "Str",
-                    string_members.get_methods(),
-                    string_members.get_statics(),
-                    string_members.get_fields(),
-                )),
-                false,
+                string_members.get_methods(),
+                string_members.get_statics(),
+                string_members.get_fields(),
             ))),
         ),
         (
@@ -68,6 +79,27 @@ pub fn load(env: Rc<RefCell<Environment>>) -> Result<(), LoxResult> {
                 number_members.get_fields(),
             ))),
         ),
+        (
+            Token::new(TokenType::Class, "Math".to_string(), None, 0),
+            Literal::Class(Rc::new(
+                LoxClass::new(
+                    "Math",
+                    math_members.get_methods(),
+                    math_members.get_statics(),
+                    math_members.get_fields(),
+                )
+                .with_constants(CONSTANTS.iter().map(|c| c.to_string()).collect()),
+            )),
+        ),
+        (
+            Token::new(TokenType::Class, "Random".to_string(), None, 0),
+            Literal::Class(Rc::new(LoxClass::new(
+                "Random",
+                random_members.get_methods(),
+                random_members.get_statics(),
+                random_members.get_fields(),
+            ))),
+        ),
     ];
 
     for (tok, native) in natives.iter() {
@@ -3,12 +3,16 @@ use std::rc::Rc;
 use std::{collections::HashMap, fmt::Display};
 
 use super::loxinstance::InstanceField;
-use super::{callable::LoxCallable, interpreter::Interpreter, loxinstance::LoxInstance};
+use super::{
+    callable::LoxCallable, environment::Environment, interpreter::Interpreter,
+    loxfunction::LoxFunction, loxinstance::LoxInstance,
+};
 use crate::error::loxerrorhandler::LoxErrorHandler;
 use crate::{
     error::{LoxErrorsTypes, LoxResult},
     lexer::literal::Literal,
     lexer::token::Token,
+    parser::{expr::Expr, stmt::Stmt},
 };
 
 #[derive(Debug, Clone, PartialEq)]
@@ -17,6 +21,14 @@ pub struct LoxClass {
     methods: HashMap<String, Literal>,
     static_fields: RefCell<HashMap<String, Literal>>,
     other_fields: Rc<RefCell<HashMap<String, InstanceField>>>,
+    // Instance field initializers, evaluated in `closure` for every new instance
+    initializers: Vec<(String, Rc<Expr>)>,
+    closure: Option<Rc<RefCell<Environment>>>,
+    getters: HashMap<String, Rc<LoxFunction>>,
+    setters: HashMap<String, Rc<LoxFunction>>,
+    // Names of the `const` static fields
+    constants: Vec<String>,
+    declaration: Option<Rc<Stmt>>,
     error_handler: LoxErrorHandler,
 }
 
@@ -32,10 +44,170 @@ impl LoxClass {
             methods,
             static_fields: RefCell::new(static_fields),
             other_fields: Rc::new(RefCell::new(other_fields)),
+            initializers: Vec::new(),
+            closure: None,
+            getters: HashMap::new(),
+            setters: HashMap::new(),
+            constants: Vec::new(),
+            declaration: None,
             error_handler: LoxErrorHandler,
         }
     }
 
+    pub fn with_accessors(
+        mut self,
+        getters: HashMap<String, Rc<LoxFunction>>,
+        setters: HashMap<String, Rc<LoxFunction>>,
+    ) -> Self {
+        self.getters = getters;
+        self.setters = setters;
+        self
+    }
+
+    pub fn with_initializers(
+        mut self,
+        initializers: Vec<(String, Rc<Expr>)>,
+        closure: Rc<RefCell<Environment>>,
+    ) -> Self {
+        self.initializers = initializers;
+        self.closure = Some(closure);
+        self
+    }
+
+    pub fn with_constants(mut self, constants: Vec<String>) -> Self {
+        self.constants = constants;
+        self
+    }
+
+    pub fn declared_by(mut self, declaration: Rc<Stmt>) -> Self {
+        self.declaration = Some(declaration);
+        self
+    }
+
+    pub fn is_declared_by(&self, classes: &[Rc<Stmt>]) -> bool {
+        match &self.declaration {
+            Some(declaration) => classes.contains(declaration),
+            None => false,
+        }
+    }
+
+    pub fn is_abstract(&self) -> bool {
+        match self.declaration.as_deref() {
+            Some(Stmt::Class(c)) => c.is_abstract,
+            _ => false,
+        }
+    }
+
+    pub fn find_getter(&self, name: &str) -> Option<Rc<LoxFunction>> {
+        self.getters.get(name).cloned()
+    }
+
+    pub fn find_setter(&self, name: &str) -> Option<Rc<LoxFunction>> {
+        self.setters.get(name).cloned()
+    }
+
+    // Returns the static getter bound to the class, `None` when the property
+    // is not computed.
+    pub fn getter(&self, name: &Token, class: &Rc<LoxClass>) -> Result<Option<Rc<LoxFunction>>, LoxResult> {
+        if self.static_fields.borrow().contains_key(&name.lexeme) {
+            return Ok(None);
+        }
+        match self.find_getter(&name.lexeme) {
+            Some(getter) => Ok(Some(self.bind_accessor(&getter, name, class)?)),
+            None if self.find_setter(&name.lexeme).is_some() => Err(self.error_handler.error(
+                name,
+                LoxErrorsTypes::ReferenceError("Cannot get write-only property".to_string()),
+            )),
+            None => Ok(None),
+        }
+    }
+
+    pub fn setter(&self, name: &Token, class: &Rc<LoxClass>) -> Result<Option<Rc<LoxFunction>>, LoxResult> {
+        if self.static_fields.borrow().contains_key(&name.lexeme) {
+            return Ok(None);
+        }
+        match self.find_setter(&name.lexeme) {
+            Some(setter) => Ok(Some(self.bind_accessor(&setter, name, class)?)),
+            None if self.find_getter(&name.lexeme).is_some() => Err(self.error_handler.error(
+                name,
+                LoxErrorsTypes::ReferenceError("Cannot set read-only property".to_string()),
+            )),
+            None => Ok(None),
+        }
+    }
+
+    fn bind_accessor(
+        &self,
+        accessor: &LoxFunction,
+        name: &Token,
+        class: &Rc<LoxClass>,
+    ) -> Result<Rc<LoxFunction>, LoxResult> {
+        if !accessor.is_static {
+            return Err(self.error_handler.error(
+                name,
+                LoxErrorsTypes::ReferenceError(
+                    "Trying to access non static property".to_string(),
+                ),
+            ));
+        }
+        accessor.bind_static(class.clone())
+    }
+
+    // Declared `init`, native classes have none.
+    pub fn initializer(&self) -> Option<Rc<LoxFunction>> {
+        match self.find_method(&"init".to_string()) {
+            Some(Literal::Func(func)) => Some(func),
+            _ => None,
+        }
+    }
+
+    // `None` arguments take the default value of the `init` parameter.
+    pub fn call_with(
+        &self,
+        interpreter: Option<&Interpreter>,
+        args: Vec<Option<Literal>>,
+    ) -> Result<Literal, LoxResult> {
+        let fields = Rc::new(RefCell::new(self.instance_fields(interpreter)?));
+        let instance = Rc::new(LoxInstance::new(self, fields));
+        let initializer = self.find_method(&"init".to_string());
+        if let Some(init) = initializer {
+            match init {
+                Literal::Func(func) => {
+                    func.bind(instance.clone())?.call_with(interpreter, args)?;
+                }
+                Literal::Native(n) => {
+                    return n.native.call(interpreter, args.into_iter().flatten().collect())
+                }
+                _ => {
+                    panic!("found non function literal in constructor")
+                }
+            }
+        }
+        Ok(Literal::Instance(instance.clone()))
+    }
+
+    // Every instance gets its own fields, so `public items = [];` is not shared.
+    fn instance_fields(
+        &self,
+        interpreter: Option<&Interpreter>,
+    ) -> Result<HashMap<String, InstanceField>, LoxResult> {
+        let mut fields = self.other_fields.borrow().clone();
+        if let (Some(interpreter), Some(closure)) = (interpreter, &self.closure) {
+            for (name, initializer) in self.initializers.iter() {
+                let value = interpreter.evaluate_in(initializer.clone(), closure.clone())?;
+                if let Some(field) = fields.get_mut(name) {
+                    field.value = value;
+                }
+            }
+        }
+        Ok(fields)
+    }
+
+    // Native classes like `Array` can take any number of arguments.
+    pub fn is_variadic(&self) -> bool {
+        matches!(self.find_method(&"init".to_string()), Some(Literal::Native(n)) if !n.check_arity)
+    }
+
     pub fn find_method(&self, name: &String) -> Option<Literal> {
         if let Some(m) = self.methods.get(name) {
             return Some(m.clone());
@@ -44,6 +216,17 @@ impl LoxClass {
         None
     }
 
+    pub fn static_fields(&self) -> Vec<(String, Literal)> {
+        let mut fields: Vec<(String, Literal)> = self
+            .static_fields
+            .borrow()
+            .iter()
+            .map(|(name, val)| (name.to_string(), val.dup()))
+            .collect();
+        fields.sort_by(|a, b| a.0.cmp(&b.0));
+        fields
+    }
+
     pub fn get(&self, name: &Token, class: &Rc<LoxClass>) -> Result<Literal, LoxResult> {
         if self.static_fields.borrow().contains_key(&name.lexeme) {
             return Ok(self.static_fields.borrow().get(&name.lexeme).unwrap().dup())
@@ -54,15 +237,14 @@ impl LoxClass {
                 if f.is_static {
                     return Ok(Literal::Func(f.bind_static(class.clone())?))
                 }
-                return Err(self.error_handler.error(
-                    name,
-                    LoxErrorsTypes::ReferenceError(
-                        "Trying to access non static property".to_string(),
-                    ),
-                ));
-            } else {
-                panic!("non function literal {method:?}")
             }
+            // Methods of native classes are only reachable through an instance
+            return Err(self.error_handler.error(
+                name,
+                LoxErrorsTypes::ReferenceError(
+                    "Trying to access non static property".to_string(),
+                ),
+            ));
         }
         return Err(self.error_handler.error(
             name,
@@ -71,6 +253,12 @@ impl LoxClass {
     }
 
     pub fn set(&self, name: &Token, val: Literal) -> Result<(), LoxResult> {
+        if self.constants.contains(&name.lexeme) {
+            return Err(self.error_handler.error(
+                name,
+                LoxErrorsTypes::Type("Cannot assign to constant field".to_string()),
+            ));
+        }
         if self.static_fields.borrow().contains_key(&name.lexeme) {
             *self
                 .static_fields
@@ -94,22 +282,7 @@ impl Display for LoxClass {
 
 impl LoxCallable for LoxClass {
     fn call(&self, interpreter: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
-        let instance = Rc::new(LoxInstance::new(self, self.other_fields.clone()));
-        let initializer = self.find_method(&"init".to_string());
-        if let Some(init) = initializer {
-            match init {
-                Literal::Func(func) => {
-                    func.bind(instance.clone())?.call(interpreter, args)?;
-                }
-                Literal::Native(n) => {
-                    return n.native.call(interpreter, args)
-                }
-                _ => {
-                    panic!("found non function literal in constructor")
-                }
-            }
-        }
-        Ok(Literal::Instance(instance.clone()))
+        self.call_with(interpreter, args.into_iter().map(Some).collect())
     }
 
     fn arity(&self) -> usize {
@@ -126,3 +299,94 @@ impl LoxCallable for LoxClass {
         0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::LoxErrorsTypes,
+        runtime::tests::{eval, run, run_err},
+    };
+
+    #[test]
+    fn non_static_methods() {
+        let expected =
+            LoxErrorsTypes::ReferenceError("Trying to access non static property".to_string());
+        assert_eq!(run_err("class Foo { init() {} public m() {} } Foo.m;"), expected);
+        assert_eq!(run_err("print(Str.len);"), expected);
+        assert_eq!(run_err("Array.push(1);"), expected);
+        assert_eq!(run_err("Str.toUpper;"), expected);
+    }
+
+    #[test]
+    fn fields_are_not_shared() {
+        let src = "class Foo { public items = []; public n = 0; init() {} }
+            let f = Foo();
+            let g = Foo();
+            f.items.push(1);
+            f.n = 3;
+            let r = [f.items, g.items, f.n, g.n];";
+        assert_eq!(eval(src, "r"), "[[1], [], 3, 0]");
+    }
+
+    #[test]
+    fn initializers_run_per_instance() {
+        let src = "let made = 0;
+            fn next() { made += 1; return made; }
+            class Foo { public id = next(); init() {} }
+            let r = [Foo().id, Foo().id, made];";
+        assert_eq!(eval(src, "r"), "[1, 2, 2]");
+    }
+
+    #[test]
+    fn static_initializer_runs_once() {
+        let src = "let made = 0;
+            fn next() { made += 1; return made; }
+            class Foo { static id = next(); init() {} }
+            Foo(); Foo();
+            let r = [Foo.id, made];";
+        assert_eq!(eval(src, "r"), "[1, 1]");
+    }
+
+    const CIRCLE: &str = "class Circle {
+        private r;
+        public sets = 0;
+        static count = 0;
+        init(r) { this.r = r; Circle.count += 1; }
+        public get area() { return this.r * this.r * 3; }
+        public get radius() { return this.r; }
+        public set radius(v) { this.r = v; this.sets += 1; }
+        public set diameter(d) { this.r = d / 2; }
+        private get secret() { return this.r; }
+        public reveal() { return this.secret; }
+        static get total() { return Circle.count; }
+        static set total(v) { Circle.count = v * 2; }
+    }";
+
+    #[test]
+    fn getters() {
+        let src = format!("{CIRCLE} let c = Circle(2);
+            let r = [c.area, c.radius, c.reveal(), Circle.total];");
+        assert_eq!(eval(&src, "r"), "[12, 2, 2, 1]");
+    }
+
+    #[test]
+    fn setters() {
+        let src = format!("{CIRCLE} let c = Circle(2);
+            c.radius = 3; c.radius += 1; c.diameter = 10; Circle.total = 4;
+            let r = [c.radius, c.sets, c.area, Circle.total];");
+        assert_eq!(eval(&src, "r"), "[5, 2, 75, 8]");
+    }
+
+    #[test]
+    fn accessor_errors() {
+        let read_only = LoxErrorsTypes::ReferenceError("Cannot set read-only property".to_string());
+        assert_eq!(run_err(&format!("{CIRCLE} Circle(1).area = 2;")), read_only);
+        let write_only =
+            LoxErrorsTypes::ReferenceError("Cannot get write-only property".to_string());
+        assert_eq!(run_err(&format!("{CIRCLE} Circle(1).diameter;")), write_only);
+        let src = "class C { static n = 0; init() {} static get total() { return C.n; } }
+            C.total = 3;";
+        assert_eq!(run_err(src), read_only);
+        assert!(run(&format!("{CIRCLE} Circle(1).secret;")).is_err());
+    }
+}
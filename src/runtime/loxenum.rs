@@ -0,0 +1,192 @@
+use std::fmt::Display;
+use std::rc::Rc;
+
+use super::{callable::LoxCallable, interpreter::Interpreter};
+use crate::error::loxerrorhandler::LoxErrorHandler;
+use crate::{
+    error::{LoxErrorsTypes, LoxResult},
+    lexer::literal::Literal,
+    lexer::token::Token,
+    loxlib::loxnatives::LoxNative,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumVariant {
+    pub name: String,
+    // `None` for variants without parentheses, `Empty` as opposed to `Empty()`
+    pub fields: Option<Vec<String>>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct LoxEnum {
+    pub name: String,
+    variants: Vec<EnumVariant>,
+    error_handler: LoxErrorHandler,
+}
+
+impl LoxEnum {
+    pub fn new(name: &str, variants: Vec<EnumVariant>) -> Self {
+        Self {
+            name: name.to_string(),
+            variants,
+            error_handler: LoxErrorHandler::new(),
+        }
+    }
+
+    pub fn find_variant(&self, name: &str) -> Option<&EnumVariant> {
+        self.variants.iter().find(|v| v.name == name)
+    }
+
+    // Unit variants are values, the others are constructors.
+    pub fn get(&self, name: &Token, owner: &Rc<LoxEnum>) -> Result<Literal, LoxResult> {
+        match self.find_variant(&name.lexeme) {
+            Some(EnumVariant { fields: None, .. }) => Ok(Literal::Variant(Rc::new(
+                LoxVariant::new(owner.clone(), &name.lexeme, Vec::new()),
+            ))),
+            Some(EnumVariant { fields: Some(fields), .. }) => {
+                let constructor = VariantConstructor {
+                    owner: owner.clone(),
+                    name: name.lexeme.to_string(),
+                    arity: fields.len(),
+                };
+                Ok(Literal::Native(Rc::new(LoxNative::new(
+                    &name.lexeme,
+                    Rc::new(constructor),
+                    true,
+                ))))
+            }
+            None => Err(self.error_handler.error(
+                name,
+                LoxErrorsTypes::ReferenceError(format!("No variant in enum '{}' named", self.name)),
+            )),
+        }
+    }
+}
+
+impl Display for LoxEnum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let variants: Vec<String> = self
+            .variants
+            .iter()
+            .map(|v| match &v.fields {
+                Some(fields) => format!("{}({})", v.name, fields.join(", ")),
+                None => v.name.to_string(),
+            })
+            .collect();
+        write!(f, "enum {} {{ {} }}", self.name, variants.join(", "))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct LoxVariant {
+    pub owner: Rc<LoxEnum>,
+    pub name: String,
+    pub values: Vec<Literal>,
+    error_handler: LoxErrorHandler,
+}
+
+impl LoxVariant {
+    pub fn new(owner: Rc<LoxEnum>, name: &str, values: Vec<Literal>) -> Self {
+        Self {
+            owner,
+            name: name.to_string(),
+            values,
+            error_handler: LoxErrorHandler::new(),
+        }
+    }
+
+    pub fn is_unit(&self) -> bool {
+        matches!(
+            self.owner.find_variant(&self.name),
+            Some(EnumVariant { fields: None, .. })
+        )
+    }
+
+    // Fields are read by the names used in the enum declaration.
+    pub fn get(&self, name: &Token) -> Result<Literal, LoxResult> {
+        let index = match self.owner.find_variant(&self.name) {
+            Some(EnumVariant { fields: Some(fields), .. }) => {
+                fields.iter().position(|f| f == &name.lexeme)
+            }
+            _ => None,
+        };
+        match index.and_then(|i| self.values.get(i)) {
+            Some(val) => Ok(val.dup()),
+            None => Err(self.error_handler.error(
+                name,
+                LoxErrorsTypes::ReferenceError(format!(
+                    "No field in '{}.{}' named",
+                    self.owner.name, self.name
+                )),
+            )),
+        }
+    }
+}
+
+pub struct VariantConstructor {
+    owner: Rc<LoxEnum>,
+    name: String,
+    arity: usize,
+}
+
+impl LoxCallable for VariantConstructor {
+    fn call(&self, _: Option<&Interpreter>, args: Vec<Literal>) -> Result<Literal, LoxResult> {
+        Ok(Literal::Variant(Rc::new(LoxVariant::new(
+            self.owner.clone(),
+            &self.name,
+            args,
+        ))))
+    }
+
+    fn arity(&self) -> usize {
+        self.arity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::LoxErrorsTypes,
+        runtime::tests::{eval, run_err},
+    };
+
+    const SHAPE: &str = "enum Shape { Circle(r), Rect(w, h), Empty }";
+
+    #[test]
+    fn variant_access() {
+        let src = format!("{SHAPE} let c = Shape.Circle(3); let rect = Shape.Rect(1, 2);
+            let r = [c.r, rect.w, rect.h, typeof(c), typeof(Shape.Empty)];");
+        assert_eq!(eval(&src, "r"), r#"[3, 1, 2, "Shape", "Shape"]"#);
+    }
+
+    #[test]
+    fn variant_equality() {
+        let src = format!("{SHAPE} enum Other {{ Circle(r) }} let c = Shape.Circle(3);
+            let r = [c == Shape.Circle(3), c != Shape.Circle(4), c == Shape.Rect(3, 3),
+                Shape.Empty == Shape.Empty, c == Other.Circle(3),
+                hash(c) == hash(Shape.Circle(3))];");
+        assert_eq!(eval(&src, "r"), "[true, true, false, true, false, true]");
+    }
+
+    #[test]
+    fn variant_printing() {
+        let src = format!("{SHAPE} let r = [Str(Shape.Circle(3)), Str(Shape.Rect(1, \"a\")),
+            Str(Shape.Empty)];");
+        let expected = r#"["Shape.Circle(3)", "Shape.Rect(1, \"a\")", "Shape.Empty"]"#;
+        assert_eq!(eval(&src, "r"), expected);
+        let src = format!("{SHAPE} let r = Str(Shape);");
+        assert_eq!(eval(&src, "r"), r#""enum Shape { Circle(r), Rect(w, h), Empty }""#);
+    }
+
+    #[test]
+    fn unknown_variant_or_field() {
+        let expected =
+            LoxErrorsTypes::ReferenceError("No variant in enum 'Shape' named".to_string());
+        assert_eq!(run_err(&format!("{SHAPE} Shape.Square;")), expected);
+        let expected =
+            LoxErrorsTypes::ReferenceError("No field in 'Shape.Circle' named".to_string());
+        assert_eq!(run_err(&format!("{SHAPE} Shape.Circle(1).w;")), expected);
+        let err = run_err(&format!("{SHAPE} Shape.Rect(1);"));
+        assert!(matches!(err, LoxErrorsTypes::Runtime(_)));
+    }
+}
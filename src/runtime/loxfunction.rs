@@ -6,7 +6,11 @@ use crate::{
     error::*,
     lexer::literal::Literal,
     lexer::token::*,
-    parser::{expr::LambdaExpr, stmt::*},
+    loxlib::array::loxarray::LoxArray,
+    parser::{
+        expr::{Expr, LambdaExpr},
+        stmt::*,
+    },
 };
 use std::{cell::RefCell, fmt::Display, rc::Rc};
 
@@ -17,6 +21,10 @@ pub struct LoxFunction {
     is_initializer: bool,
     pub name: Option<Token>,
     params: Rc<Vec<Token>>,
+    // Default values of the trailing parameters, evaluated on each call
+    defaults: Rc<Vec<Rc<Expr>>>,
+    // The last parameter collects the remaining arguments in an array
+    pub is_variadic: bool,
     body: Rc<Vec<Rc<Stmt>>>,
     closure: Rc<RefCell<Environment>>,
 }
@@ -32,6 +40,8 @@ impl LoxFunction {
         Self {
             name: Some(decl.name.dup()),
             params: Rc::clone(&decl.params),
+            defaults: Rc::clone(&decl.defaults),
+            is_variadic: decl.is_variadic,
             body: decl.body.clone(),
             closure: Rc::clone(env),
             is_initializer,
@@ -44,6 +54,8 @@ impl LoxFunction {
         Self {
             name: None,
             params: Rc::clone(&decl.params),
+            defaults: Rc::clone(&decl.defaults),
+            is_variadic: decl.is_variadic,
             body: Rc::clone(&decl.body),
             closure: Rc::clone(env),
             is_initializer: false,
@@ -52,12 +64,70 @@ impl LoxFunction {
         }
     }
 
+    pub fn params(&self) -> &[Token] {
+        &self.params
+    }
+
+    // Number of parameters without a default value.
+    pub fn required_arity(&self) -> usize {
+        self.fixed_arity() - self.defaults.len()
+    }
+
+    // Number of parameters before the rest parameter.
+    pub fn fixed_arity(&self) -> usize {
+        self.params.len() - usize::from(self.is_variadic)
+    }
+
+    // Missing arguments take their default value, evaluated in the scope of
+    // the call so earlier parameters can be referenced.
+    pub fn call_with(
+        &self,
+        interpreter: Option<&Interpreter>,
+        args: Vec<Option<Literal>>,
+    ) -> Result<Literal, LoxResult> {
+        let interpreter = interpreter.expect("Interpreter was not provided for LoxFunction");
+        let env = Rc::new(RefCell::new(Environment::new_enclosing(self.closure.clone())));
+        let required = self.required_arity();
+        let mut args = args.into_iter();
+        for (i, param) in self.params[..self.fixed_arity()].iter().enumerate() {
+            let val = match args.next().flatten() {
+                Some(val) => val,
+                None if i >= required => {
+                    let default = self.defaults[i - required].clone();
+                    let prev = interpreter.environment.replace(env.clone());
+                    let val = interpreter.evaluate(default);
+                    interpreter.environment.replace(prev);
+                    val?
+                }
+                None => continue,
+            };
+            env.borrow_mut().define(param, val)?;
+        }
+        if let (true, Some(rest)) = (self.is_variadic, self.params.last()) {
+            let rest_arr = LoxArray::new(args.flatten().collect());
+            env.borrow_mut().define(rest, Literal::Array(Rc::new(rest_arr)))?;
+        }
+        if let Err(ret_val) = interpreter.execute_block_in(&self.body, env) {
+            match ret_val {
+                LoxResult::Return(value) => return Ok(value),
+                _ => return Err(ret_val),
+            }
+        }
+
+        if self.is_initializer {
+            return self.closure.borrow().get_at(0, &Token::this());
+        }
+        Ok(Literal::None)
+    }
+
     pub fn bind(&self, instance: Rc<LoxInstance>) -> Result<Rc<Self>, LoxResult> {
         let mut env = Environment::new_enclosing(self.closure.clone());
         env.define(&Token::this(), Literal::Instance(instance))?;
         Ok(Rc::new(LoxFunction {
             name: self.name.clone(),
             params: self.params.clone(),
+            defaults: self.defaults.clone(),
+            is_variadic: self.is_variadic,
             body: self.body.clone(),
             closure: Rc::new(RefCell::new(env)),
             is_initializer: self.is_initializer,
@@ -72,6 +142,8 @@ impl LoxFunction {
         Ok(Rc::new(LoxFunction {
             name: self.name.clone(),
             params: self.params.clone(),
+            defaults: self.defaults.clone(),
+            is_variadic: self.is_variadic,
             body: self.body.clone(),
             closure: Rc::new(RefCell::new(env)),
             is_initializer: self.is_initializer,
@@ -96,26 +168,7 @@ impl LoxCallable for LoxFunction {
         interpreter: Option<&Interpreter>,
         args: Vec<Literal>,
     ) -> Result<Literal, LoxResult> {
-        let mut env = Environment::new_enclosing(self.closure.clone());
-        for (i, d) in self.params.iter().enumerate() {
-            if let Some(val) = args.get(i) {
-                env.define(d, val.dup())?;
-            }
-        }
-        if let Err(ret_val) = interpreter
-            .expect("Interpreter was not provided for LoxFunction")
-            .execute_block(&self.body, env)
-        {
-            match ret_val {
-                LoxResult::Return(value) => return Ok(value),
-                _ => return Err(ret_val),
-            }
-        }
-
-        if self.is_initializer {
-            return Ok(self.closure.borrow().get_at(0, &Token::this())?);
-        }
-        Ok(Literal::None)
+        self.call_with(interpreter, args.into_iter().map(Some).collect())
     }
 
     fn arity(&self) -> usize {
@@ -2,18 +2,20 @@ use crate::{
     error::{loxerrorhandler::LoxErrorHandler, LoxErrorsTypes, LoxResult},
     lexer::literal::Literal,
     lexer::token::Token,
+    parser::stmt::Stmt,
 };
 use std::{collections::HashMap, fmt::Display};
 
 use std::cell::RefCell;
 use std::rc::Rc;
 
-use super::loxclass::LoxClass;
+use super::{loxclass::LoxClass, loxfunction::LoxFunction};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct InstanceField {
     pub value: Literal,
     pub is_public: bool,
+    pub is_const: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -21,7 +23,6 @@ pub struct LoxInstance {
     klass: LoxClass,
     fields: Rc<RefCell<HashMap<String, InstanceField>>>,
     error_handler: LoxErrorHandler,
-    pub this: RefCell<bool>,
 }
 
 impl LoxInstance {
@@ -30,11 +31,104 @@ impl LoxInstance {
             klass: klass.clone(),
             fields,
             error_handler: LoxErrorHandler::new(),
-            this: RefCell::new(false),
         }
     }
 
-    pub fn get(&self, name: &Token, this: &Rc<LoxInstance>) -> Result<Literal, LoxResult> {
+    pub fn class_name(&self) -> &str {
+        &self.klass.name
+    }
+
+    pub fn is_declared_by(&self, classes: &[Rc<Stmt>]) -> bool {
+        self.klass.is_declared_by(classes)
+    }
+
+    pub fn find_method(&self, name: &str) -> Option<Literal> {
+        self.klass.find_method(&name.to_string())
+    }
+
+    pub fn public_fields(&self) -> Vec<(String, Literal)> {
+        let mut fields: Vec<(String, Literal)> = self
+            .fields
+            .borrow()
+            .iter()
+            .filter(|(_, field)| field.is_public)
+            .map(|(name, field)| (name.to_string(), field.value.dup()))
+            .collect();
+        fields.sort_by(|a, b| a.0.cmp(&b.0));
+        fields
+    }
+
+    // Returns the getter bound to this instance, `None` when the property is
+    // not computed. `private_access` is true when the access is lexically
+    // inside the class declaration.
+    pub fn getter(
+        &self,
+        name: &Token,
+        this: &Rc<LoxInstance>,
+        private_access: bool,
+    ) -> Result<Option<Rc<LoxFunction>>, LoxResult> {
+        if self.fields.borrow().contains_key(&name.lexeme) {
+            return Ok(None);
+        }
+        match self.klass.find_getter(&name.lexeme) {
+            Some(getter) => Ok(Some(self.bind_accessor(&getter, name, this, private_access)?)),
+            None if self.klass.find_setter(&name.lexeme).is_some() => Err(self.error_handler.error(
+                name,
+                LoxErrorsTypes::ReferenceError("Cannot get write-only property".to_string()),
+            )),
+            None => Ok(None),
+        }
+    }
+
+    pub fn setter(
+        &self,
+        name: &Token,
+        this: &Rc<LoxInstance>,
+        private_access: bool,
+    ) -> Result<Option<Rc<LoxFunction>>, LoxResult> {
+        if self.fields.borrow().contains_key(&name.lexeme) {
+            return Ok(None);
+        }
+        match self.klass.find_setter(&name.lexeme) {
+            Some(setter) => Ok(Some(self.bind_accessor(&setter, name, this, private_access)?)),
+            None if self.klass.find_getter(&name.lexeme).is_some() => Err(self.error_handler.error(
+                name,
+                LoxErrorsTypes::ReferenceError("Cannot set read-only property".to_string()),
+            )),
+            None => Ok(None),
+        }
+    }
+
+    fn bind_accessor(
+        &self,
+        accessor: &LoxFunction,
+        name: &Token,
+        this: &Rc<LoxInstance>,
+        private_access: bool,
+    ) -> Result<Rc<LoxFunction>, LoxResult> {
+        if accessor.is_static {
+            return Err(self.error_handler.error(
+                name,
+                LoxErrorsTypes::Runtime(
+                    "Cannot access static property from instantiated class".to_string(),
+                ),
+            ));
+        }
+        if !(accessor.is_pub || private_access) {
+            return Err(self.error_handler.error(
+                name,
+                LoxErrorsTypes::ReferenceError("Cannot access private property".to_string()),
+            ));
+        }
+        accessor.bind(this.clone())
+    }
+
+    pub fn get(
+        &self,
+        name: &Token,
+        this: &Rc<LoxInstance>,
+        private_access: bool,
+    ) -> Result<Literal, LoxResult> {
         if self.fields.borrow().contains_key(&name.lexeme) {
             if !(self
                 .fields
@@ -43,14 +137,13 @@ impl LoxInstance {
                 .as_ref()
                 .unwrap()
                 .is_public
-                || self.this.borrow().eq(&true))
+                || private_access)
             {
                 return Err(self.error_handler.error(
                     name,
                     LoxErrorsTypes::ReferenceError("Cannot get private property".to_string()),
                 ));
             }
-            *self.this.borrow_mut() = false;
             return Ok(self.fields.borrow().get(&name.lexeme).unwrap().value.dup());
         }
 
@@ -65,7 +158,7 @@ impl LoxInstance {
                             ),
                         ));
                     }
-                    if !(method.is_pub || self.this.borrow().eq(&true)) {
+                    if !(method.is_pub || private_access) {
                         return Err(self.error_handler.error(
                             name,
                             LoxErrorsTypes::Runtime("Cannot call private method".to_string()),
@@ -86,7 +179,7 @@ impl LoxInstance {
         ))
     }
 
-    pub fn set(&self, name: &Token, val: Literal) -> Result<(), LoxResult> {
+    pub fn set(&self, name: &Token, val: Literal, private_access: bool) -> Result<(), LoxResult> {
         if self.fields.borrow().contains_key(&name.lexeme) {
             if !(self
                 .fields
@@ -95,14 +188,19 @@ impl LoxInstance {
                 .as_ref()
                 .unwrap()
                 .is_public
-                || self.this.borrow().eq(&true))
+                || private_access)
             {
                 return Err(self.error_handler.error(
                     name,
                     LoxErrorsTypes::ReferenceError("Cannot set private property".to_string()),
                 ));
             }
-            *self.this.borrow_mut() = false;
+            if self.fields.borrow()[&name.lexeme].is_const {
+                return Err(self.error_handler.error(
+                    name,
+                    LoxErrorsTypes::Type("Cannot assign to constant field".to_string()),
+                ));
+            }
             self.fields
                 .borrow_mut()
                 .get_mut(&name.lexeme)
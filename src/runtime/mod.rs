@@ -6,3 +6,73 @@ pub mod loxfunction;
 pub mod resolver;
 pub mod loxclass;
 pub mod loxinstance;
+pub mod loxenum;
+
+#[cfg(test)]
+pub mod tests {
+    use crate::{
+        error::{loxerrorhandler::LoxErrorHandler, LoxErrorsTypes, LoxResult, LoxWarningTypes},
+        lexer::{literal::Literal, scanner::Scanner, token::Token, tokentype::TokenType},
+        parser::rdp::Parser,
+    };
+
+    use super::{interpreter::Interpreter, resolver::Resolver};
+
+    // Runs `source` as a script and returns the interpreter to read the globals from.
+    // The error is `None` when the resolver reported it without stopping.
+    pub fn run(source: &str) -> Result<Interpreter, Option<LoxErrorsTypes>> {
+        let error_handler = LoxErrorHandler::new();
+        let mut scanner = Scanner::new(source, &error_handler);
+        let tokens = scanner.scan_tokens().map_err(error_type)?;
+        let stmts = Parser::new(tokens).parse().map_err(error_type)?;
+
+        let mut interpreter = Interpreter::new();
+        let resolver = Resolver::new(&interpreter);
+        resolver.resolve(&stmts).map_err(error_type)?;
+        if *resolver.had_error.borrow() {
+            return Err(None);
+        }
+        interpreter.interpret(stmts).map_err(error_type)?;
+        Ok(interpreter)
+    }
+
+    // The warnings the resolver reports for `source`, without running it.
+    pub fn warnings(source: &str) -> Vec<LoxWarningTypes> {
+        let error_handler = LoxErrorHandler::new();
+        let mut scanner = Scanner::new(source, &error_handler);
+        let tokens = scanner.scan_tokens().unwrap_or_else(|_| panic!("failed {source}"));
+        let stmts = Parser::new(tokens).parse().unwrap_or_else(|_| panic!("failed {source}"));
+
+        let interpreter = Interpreter::new();
+        let resolver = Resolver::new(&interpreter);
+        resolver.resolve(&stmts).unwrap_or_else(|_| panic!("failed {source}"));
+        let warnings = resolver.warnings.take();
+        warnings.into_iter().map(|warning| warning.warning_type).collect()
+    }
+
+    pub fn run_err(source: &str) -> LoxErrorsTypes {
+        match run(source) {
+            Ok(_) => panic!("expected an error running {source}"),
+            Err(err) => err.unwrap_or_else(|| panic!("no error type for {source}")),
+        }
+    }
+
+    pub fn global(interpreter: &Interpreter, name: &str) -> Literal {
+        let name = Token::new(TokenType::Identifier, name.to_string(), None, 0);
+        interpreter.globals.borrow().get(&name).unwrap()
+    }
+
+    // The repr of the global `name` after running `source`.
+    pub fn eval(source: &str, name: &str) -> String {
+        let interpreter = run(source).unwrap_or_else(|_| panic!("failed {source}"));
+        let value = global(&interpreter, name);
+        interpreter.repr(&value).unwrap()
+    }
+
+    fn error_type(err: LoxResult) -> Option<LoxErrorsTypes> {
+        match err {
+            LoxResult::Error(err) => Some(err.error_type),
+            _ => None,
+        }
+    }
+}
@@ -6,6 +6,7 @@ use crate::{
     error::{loxerrorhandler::LoxErrorHandler, loxwarninghandler::LoxWarningHandler, *},
     lexer::token::Token,
     parser::expr::*,
+    parser::pattern::Pattern,
     parser::stmt::*,
 };
 
@@ -30,6 +31,9 @@ struct VariableType {
     token: Option<Token>,
     define: bool,
     used: bool,
+    is_const: bool,
+    // Set when the binding is a class declaration
+    class: Option<Rc<ClassInfo>>,
 }
 
 impl VariableType {
@@ -38,6 +42,8 @@ impl VariableType {
             token: Some(tok),
             define: d,
             used: u,
+            is_const: false,
+            class: None,
         }
     }
 }
@@ -52,12 +58,32 @@ enum Returned {
 enum ClassType {
     None,
     Class,
+    // Field initializers are resolved outside of the methods, there is no `this` to capture.
+    Field,
 }
+// Method names and arities of an interface
+type Signatures = Vec<(String, usize)>;
+
+#[derive(Debug, PartialEq)]
+struct ClassInfo {
+    declaration: Rc<Stmt>,
+    // Visibility of every member, true when public.
+    members: HashMap<String, bool>,
+    is_abstract: bool,
+}
+
 pub struct Resolver<'a> {
     pub had_error: RefCell<bool>,
+    // Warnings reported so far, they don't stop the script from running.
+    pub warnings: RefCell<Vec<LoxWarning>>,
     interpreter: &'a Interpreter,
     scopes: RefCell<Vec<RefCell<HashMap<String, VariableType>>>>,
-    classes: RefCell<HashMap<String, ()>>,
+    // Interfaces declared in each scope, the top level ones live in the interpreter.
+    interfaces: RefCell<Vec<HashMap<String, Signatures>>>,
+    // Constants and classes declared at the top level, where there are no scopes.
+    global_constants: RefCell<Vec<String>>,
+    global_classes: RefCell<HashMap<String, Rc<ClassInfo>>>,
+    enclosing_classes: RefCell<Vec<Rc<Stmt>>>,
     error_handler: LoxErrorHandler,
     warning_handler: LoxWarningHandler,
     current_fn: RefCell<FnType>,
@@ -71,12 +97,16 @@ impl<'a> Resolver<'a> {
         Self {
             interpreter,
             scopes: RefCell::new(Vec::new()),
-            classes: RefCell::new(HashMap::new()),
+            interfaces: RefCell::new(Vec::new()),
+            global_constants: RefCell::new(Vec::new()),
+            global_classes: RefCell::new(HashMap::new()),
+            enclosing_classes: RefCell::new(Vec::new()),
             error_handler: LoxErrorHandler::new(),
             current_fn: RefCell::new(FnType::None),
             returned: RefCell::new(Returned::None),
             current_loop: RefCell::new(LoopType::None),
             had_error: RefCell::new(false),
+            warnings: RefCell::new(Vec::new()),
             warning_handler: LoxWarningHandler::new(),
             current_class: RefCell::new(ClassType::None),
         }
@@ -87,13 +117,37 @@ impl<'a> Resolver<'a> {
     }
 
     fn resolve_function(&self, function: &FunctionStmt, fn_type: FnType) -> Result<(), LoxResult> {
+        self.resolve_callable(
+            &function.name.lexeme,
+            &function.params,
+            &function.defaults,
+            function.is_variadic,
+            &function.body,
+            fn_type,
+        )
+    }
+
+    fn resolve_callable(
+        &self,
+        name: &str,
+        params: &[Token],
+        defaults: &[Rc<Expr>],
+        is_variadic: bool,
+        body: &[Rc<Stmt>],
+        fn_type: FnType,
+    ) -> Result<(), LoxResult> {
         let enclosing_fn = self.current_fn.replace(fn_type);
         self.begin_scope();
-        for param in function.params.iter() {
+        let required = params.len() - defaults.len() - usize::from(is_variadic);
+        for (i, param) in params.iter().enumerate() {
+            // Defaults can refer to the parameters before them
+            if i >= required && i - required < defaults.len() {
+                self.resolve_expr(defaults[i - required].clone())?;
+            }
             self.declare(param);
             self.define(param);
         }
-        for (i, s) in function.body.iter().enumerate() {
+        for (i, s) in body.iter().enumerate() {
             if self.current_fn.borrow().eq(&FnType::Function)
                 || self.current_fn.borrow().eq(&FnType::Method)
             {
@@ -104,16 +158,16 @@ impl<'a> Resolver<'a> {
                 };
                 unsafe {
                     match *self.returned.as_ptr() {
-                        Returned::Return(line) if function.body.len() - 1 > i => {
-                            self.warning_handler.simple_warning(
+                        Returned::Return(line) if body.len() - 1 > i => {
+                            self.record(self.warning_handler.simple_warning(
                                 line + 1,
                                 LoxWarningTypes::DeadCode(format!(
                                     "Found unreachable code after line '{}' in {} '{}'",
                                     line + 1,
                                     fn_type_str,
-                                    function.name.lexeme
+                                    name
                                 )),
-                            );
+                            ));
                             break;
                         }
                         _ => {}
@@ -135,11 +189,13 @@ impl<'a> Resolver<'a> {
 
     fn begin_scope(&self) {
         self.scopes.borrow_mut().push(RefCell::new(HashMap::new()));
+        self.interfaces.borrow_mut().push(HashMap::new());
     }
 
     fn end_scope(&self) {
         self.check_unused();
         self.scopes.borrow_mut().pop();
+        self.interfaces.borrow_mut().pop();
     }
 
     // fn print_scopes(&self) {
@@ -156,16 +212,56 @@ impl<'a> Resolver<'a> {
         Ok(())
     }
 
+    fn resolve_pattern(&self, pattern: &Pattern) -> Result<(), LoxResult> {
+        match pattern {
+            Pattern::Array(_, patterns) => {
+                for p in patterns {
+                    self.resolve_pattern(p)?;
+                }
+            }
+            Pattern::Variant {
+                enum_expr, fields, ..
+            } => {
+                self.resolve_expr(enum_expr.clone())?;
+                for p in fields.iter().flatten() {
+                    self.resolve_pattern(p)?;
+                }
+            }
+            Pattern::Or(patterns) => {
+                for p in patterns {
+                    if let Some(name) = p.bindings().first() {
+                        self.had_error.replace(true);
+                        self.error_handler.error(
+                            name,
+                            LoxErrorsTypes::Syntax(
+                                "Cannot bind variables in alternative patterns, found".to_string(),
+                            ),
+                        );
+                    }
+                    self.resolve_pattern(p)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn record(&self, warning: LoxResult) {
+        if let LoxResult::Warning(warning) = warning {
+            self.warnings.borrow_mut().push(warning);
+        }
+    }
+
     fn check_unused(&self) {
         if self.scopes.borrow().is_empty() {
             return;
         }
         for var in self.scopes.borrow().last().unwrap().borrow().values() {
             if !var.used {
-                self.warning_handler.warn(
+                self.record(self.warning_handler.warn(
                     var.token.as_ref().unwrap(),
                     LoxWarningTypes::UnusedVariable("Unused variable".to_string()),
-                );
+                ));
             }
         }
     }
@@ -196,6 +292,35 @@ impl<'a> Resolver<'a> {
         );
     }
 
+    fn define_const(&self, name: &Token) {
+        if self.scopes.borrow().is_empty() {
+            self.global_constants.borrow_mut().push(name.lexeme.to_string());
+            return;
+        }
+        self.define(name);
+        if let Some(var) = self.scopes.borrow().last().unwrap().borrow_mut().get_mut(&name.lexeme) {
+            var.is_const = true;
+        }
+    }
+
+    // The innermost declaration decides, a local can shadow a constant.
+    fn check_assignable(&self, name: &Token) {
+        let is_const = self
+            .scopes
+            .borrow()
+            .iter()
+            .rev()
+            .find_map(|scope| scope.borrow().get(&name.lexeme).map(|var| var.is_const))
+            .unwrap_or_else(|| self.global_constants.borrow().contains(&name.lexeme));
+        if is_const {
+            self.had_error.replace(true);
+            self.error_handler.error(
+                name,
+                LoxErrorsTypes::Type("Cannot assign to constant".to_string()),
+            );
+        }
+    }
+
     fn define(&self, name: &Token) {
         if self.scopes.borrow().is_empty() {
             return;
@@ -211,6 +336,146 @@ impl<'a> Resolver<'a> {
             .define = true;
     }
 
+    fn bind_class(&self, name: &Token, class: Rc<ClassInfo>) {
+        match self.scopes.borrow().last() {
+            Some(scope) => {
+                if let Some(var) = scope.borrow_mut().get_mut(&name.lexeme) {
+                    var.class = Some(class);
+                }
+            }
+            None => {
+                self.global_classes
+                    .borrow_mut()
+                    .insert(name.lexeme.to_string(), class);
+            }
+        }
+    }
+
+    // The class declaration `name` refers to, `None` when the innermost
+    // binding of the name is not a class.
+    fn class_binding(&self, name: &Token) -> Option<Rc<ClassInfo>> {
+        for scope in self.scopes.borrow().iter().rev() {
+            if let Some(var) = scope.borrow().get(&name.lexeme) {
+                return var.class.clone();
+            }
+        }
+        self.global_classes.borrow().get(&name.lexeme).cloned()
+    }
+
+    // Private members are only reachable lexically inside their class, the
+    // interpreter checks instances against the recorded classes. Accesses on a
+    // constructor call, `Point(1, 2).x`, are checked here.
+    fn resolve_access(&self, wrapper: Rc<Expr>, object: &Expr, name: &Token) -> Result<(), LoxResult> {
+        let enclosing = self.enclosing_classes.borrow().clone();
+        if let Expr::Call(call) = object {
+            if let Expr::Variable(class) = &*call.callee {
+                let (is_private, is_inside) = match self.class_binding(&class.name) {
+                    Some(info) => (
+                        info.members.get(&name.lexeme) == Some(&false),
+                        enclosing.contains(&info.declaration),
+                    ),
+                    None => (false, false),
+                };
+                if is_private && !is_inside {
+                    return Err(self.error_handler.error(
+                        name,
+                        LoxErrorsTypes::ReferenceError(format!(
+                            "Cannot access private property outside of class '{}' for",
+                            class.name.lexeme
+                        )),
+                    ));
+                }
+            }
+        }
+
+        if !enclosing.is_empty() {
+            self.interpreter.resolve_access(wrapper, enclosing);
+        }
+        Ok(())
+    }
+
+    fn interface(&self, name: &str) -> Option<Signatures> {
+        for scope in self.interfaces.borrow().iter().rev() {
+            if let Some(methods) = scope.get(name) {
+                return Some(methods.clone());
+            }
+        }
+        self.interpreter.interface(name)
+    }
+
+    // Every method listed by an implemented interface has to be declared public
+    // with the same arity, abstract classes may leave them out.
+    fn check_interfaces(&self, stmt: &ClassStmt) -> Result<(), LoxResult> {
+        for interface in stmt.interfaces.iter() {
+            let signatures = match self.interface(&interface.lexeme) {
+                Some(signatures) => signatures,
+                None => {
+                    return Err(self.error_handler.error(
+                        interface,
+                        LoxErrorsTypes::ReferenceError("Undefined interface".to_string()),
+                    ))
+                }
+            };
+
+            for (name, arity) in signatures.iter() {
+                let method = stmt.methods.iter().find_map(|m| match &**m {
+                    Stmt::Function(f) if &f.name.lexeme == name => {
+                        Some((f.name.dup(), f.params.len(), f.is_pub && !f.is_static))
+                    }
+                    Stmt::Signature(s) if &s.name.lexeme == name => {
+                        Some((s.name.dup(), s.params.len(), s.is_pub))
+                    }
+                    _ => None,
+                });
+                let error = match method {
+                    None if stmt.is_abstract => continue,
+                    None => (
+                        stmt.name.dup(),
+                        format!("Missing method '{name}' of interface '{}' in class", interface.lexeme),
+                    ),
+                    Some((token, params, _)) if params != *arity => (
+                        token,
+                        format!(
+                            "Expected {arity} parameters as declared by interface '{}' but got {params} for",
+                            interface.lexeme
+                        ),
+                    ),
+                    Some((token, _, false)) => (
+                        token,
+                        format!(
+                            "Expected public non static method as declared by interface '{}' for",
+                            interface.lexeme
+                        ),
+                    ),
+                    Some(_) => continue,
+                };
+                self.error_handler.error(&error.0, LoxErrorsTypes::Type(error.1));
+                self.had_error.replace(true);
+            }
+        }
+        Ok(())
+    }
+
+    fn class_members(stmt: &ClassStmt) -> HashMap<String, bool> {
+        let mut members = HashMap::new();
+        for member in stmt
+            .fields
+            .iter()
+            .chain(stmt.methods.iter())
+            .chain(stmt.getters.iter())
+            .chain(stmt.setters.iter())
+        {
+            let (name, is_pub) = match &**member {
+                Stmt::Field(f) => (f.name.lexeme.to_string(), f.is_pub),
+                Stmt::Function(f) => (f.name.lexeme.to_string(), f.is_pub),
+                Stmt::Signature(s) => (s.name.lexeme.to_string(), s.is_pub),
+                _ => continue,
+            };
+            *members.entry(name).or_insert(false) |= is_pub;
+        }
+        members
+    }
+
     fn resolve_local(&self, expr: Rc<Expr>, name: &Token) {
         for (i, scope) in self.scopes.borrow().iter().rev().enumerate() {
             if scope.borrow().contains_key(&name.lexeme) {
@@ -228,6 +493,56 @@ impl<'a> VisitorExpr<()> for Resolver<'a> {
         Ok(())
     }
 
+    fn visit_optional_expr(&self, _: Rc<Expr>, expr: &OptionalExpr, _: u16) -> Result<(), LoxResult> {
+        self.resolve_expr(expr.object.clone())
+    }
+
+    fn visit_chain_expr(&self, _: Rc<Expr>, expr: &ChainExpr, _: u16) -> Result<(), LoxResult> {
+        self.resolve_expr(expr.expr.clone())
+    }
+
+    fn visit_spread_expr(&self, _: Rc<Expr>, expr: &SpreadExpr, _: u16) -> Result<(), LoxResult> {
+        self.resolve_expr(expr.expr.clone())
+    }
+
+    fn visit_slice_expr(&self, _: Rc<Expr>, expr: &SliceExpr, _: u16) -> Result<(), LoxResult> {
+        for part in [&expr.start, &expr.end, &expr.step].into_iter().flatten() {
+            self.resolve_expr(part.clone())?;
+        }
+        Ok(())
+    }
+
+    fn visit_match_expr(&self, _: Rc<Expr>, expr: &MatchExpr, _: u16) -> Result<(), LoxResult> {
+        self.resolve_expr(expr.value.clone())?;
+
+        let mut catch_all = false;
+        for arm in expr.arms.iter() {
+            if catch_all {
+                self.record(self.warning_handler.warn(
+                    &arm.arrow,
+                    LoxWarningTypes::DeadCode(
+                        "Unreachable match arm after wildcard pattern".to_string(),
+                    ),
+                ));
+            }
+            self.resolve_pattern(&arm.pattern)?;
+
+            self.begin_scope();
+            for name in arm.pattern.bindings() {
+                self.declare(&name);
+                self.define(&name);
+            }
+            if let Some(guard) = &arm.guard {
+                self.resolve_expr(guard.clone())?;
+            }
+            self.resolve_statement(arm.body.clone())?;
+            self.end_scope();
+
+            catch_all = catch_all || (arm.guard.is_none() && arm.pattern.is_irrefutable());
+        }
+        Ok(())
+    }
+
     fn visit_array_expr(&self, _: Rc<Expr>, expr: &ArrayExpr, _: u16) -> Result<(), LoxResult> {
         for el in expr.arr.iter() {
             self.resolve_expr(el.clone())?;
@@ -238,6 +553,15 @@ impl<'a> VisitorExpr<()> for Resolver<'a> {
     fn visit_call_expr(&self, _: Rc<Expr>, expr: &CallExpr, _: u16) -> Result<(), LoxResult> {
         self.resolve_expr(expr.callee.clone())?;
 
+        if let Expr::Variable(class) = &*expr.callee {
+            if self.class_binding(&class.name).is_some_and(|info| info.is_abstract) {
+                return Err(self.error_handler.error(
+                    &class.name,
+                    LoxErrorsTypes::Type("Cannot instantiate abstract class".to_string()),
+                ));
+            }
+        }
+
         for arg in expr.args.iter() {
             self.resolve_expr(arg.clone())?;
         }
@@ -263,13 +587,20 @@ impl<'a> VisitorExpr<()> for Resolver<'a> {
         _: u16,
     ) -> Result<(), LoxResult> {
         self.resolve_expr(expr.value.clone())?;
+        self.check_assignable(&expr.name);
         self.resolve_local(wrapper.clone(), &expr.name);
         Ok(())
     }
 
     fn visit_lambda_expr(&self, _: Rc<Expr>, expr: &LambdaExpr, _: u16) -> Result<(), LoxResult> {
-        self.resolve(&*expr.body)?;
-        Ok(())
+        self.resolve_callable(
+            "lambda",
+            &expr.params,
+            &expr.defaults,
+            expr.is_variadic,
+            &expr.body,
+            FnType::Function,
+        )
     }
 
     fn visit_logical_expr(&self, _: Rc<Expr>, expr: &LogicalExpr, _: u16) -> Result<(), LoxResult> {
@@ -341,23 +672,29 @@ impl<'a> VisitorExpr<()> for Resolver<'a> {
         _: u16,
     ) -> Result<(), LoxResult> {
         self.resolve_expr(expr.value.clone())?;
+        self.check_assignable(&expr.name);
         self.resolve_local(wrapper.clone(), &expr.name);
         Ok(())
     }
 
-    fn visit_get_expr(&self, _: Rc<Expr>, expr: &GetExpr, _: u16) -> Result<(), LoxResult> {
+    fn visit_get_expr(&self, wrapper: Rc<Expr>, expr: &GetExpr, _: u16) -> Result<(), LoxResult> {
         self.resolve_expr(expr.object.clone())?;
+        self.resolve_access(wrapper, &expr.object, &expr.name)?;
         Ok(())
     }
 
-    fn visit_set_expr(&self, _: Rc<Expr>, expr: &SetExpr, _: u16) -> Result<(), LoxResult> {
+    fn visit_set_expr(&self, wrapper: Rc<Expr>, expr: &SetExpr, _: u16) -> Result<(), LoxResult> {
         self.resolve_expr(expr.value.clone())?;
         self.resolve_expr(expr.object.clone())?;
+        self.resolve_access(wrapper, &expr.object, &expr.name)?;
         Ok(())
     }
 
     fn visit_update_expr(&self, _: Rc<Expr>, expr: &UpdateExpr, _: u16) -> Result<(), LoxResult> {
         self.resolve_expr(expr.var.clone())?;
+        if let Expr::Variable(var) = &*expr.var {
+            self.check_assignable(&var.name);
+        }
         Ok(())
     }
 
@@ -368,6 +705,12 @@ impl<'a> VisitorExpr<()> for Resolver<'a> {
                 LoxErrorsTypes::Syntax("Cannot use 'this' outside of a class".to_string()),
             ));
         }
+        if self.current_class.borrow().eq(&ClassType::Field) {
+            return Err(self.error_handler.error(
+                &expr.keyword,
+                LoxErrorsTypes::Syntax("Cannot use 'this' in a field initializer".to_string()),
+            ));
+        }
         self.resolve_local(wrapper.clone(), &expr.keyword);
         Ok(())
     }
@@ -396,13 +739,34 @@ impl<'a> VisitorStmt<()> for Resolver<'a> {
         Ok(())
     }
 
+    fn visit_destructure_stmt(
+        &self,
+        _: Rc<Stmt>,
+        stmt: &DestructureStmt,
+        _: u16,
+    ) -> Result<(), LoxResult> {
+        let names = stmt.target.bindings();
+        for name in names.iter() {
+            self.declare(name);
+        }
+        self.resolve_expr(stmt.initializer.clone())?;
+        for name in names.iter() {
+            self.define(name);
+        }
+        Ok(())
+    }
+
     fn visit_let_stmt(&self, _: Rc<Stmt>, stmt: &LetStmt, _: u16) -> Result<(), LoxResult> {
         self.declare(&stmt.name);
 
         if let Some(init) = &stmt.initializer {
             self.resolve_expr(init.clone())?;
         }
-        self.define(&stmt.name);
+        if stmt.is_const {
+            self.define_const(&stmt.name);
+        } else {
+            self.define(&stmt.name);
+        }
         Ok(())
     }
 
@@ -517,15 +881,23 @@ impl<'a> VisitorStmt<()> for Resolver<'a> {
         Ok(())
     }
 
-    fn visit_class_stmt(&self, _: Rc<Stmt>, stmt: &ClassStmt, _: u16) -> Result<(), LoxResult> {
+    fn visit_class_stmt(&self, wrapper: Rc<Stmt>, stmt: &ClassStmt, _: u16) -> Result<(), LoxResult> {
         self.declare(&stmt.name);
         self.define(&stmt.name);
-        self.classes
-            .borrow_mut()
-            .insert(stmt.name.lexeme.to_string(), ());
+        let info = Rc::new(ClassInfo {
+            declaration: wrapper.clone(),
+            members: Resolver::class_members(stmt),
+            is_abstract: stmt.is_abstract,
+        });
+        self.bind_class(&stmt.name, info);
+        self.check_interfaces(stmt)?;
+        self.enclosing_classes.borrow_mut().push(wrapper);
+
+        let prev = self.current_class.replace(ClassType::Field);
+        self.resolve(&stmt.fields)?;
+        self.current_class.replace(ClassType::Class);
 
         self.begin_scope();
-        let prev = self.current_class.replace(ClassType::Class);
         self.scopes
             .borrow_mut()
             .last()
@@ -537,9 +909,17 @@ impl<'a> VisitorStmt<()> for Resolver<'a> {
                     token: None,
                     define: true,
                     used: true,
+                    is_const: false,
+                    class: None,
                 },
             );
 
+        for accessor in stmt.getters.iter().chain(stmt.setters.iter()) {
+            if let Stmt::Function(f) = &**accessor {
+                self.resolve_function(f, FnType::Method)?;
+            }
+        }
+
         for method in stmt.methods.iter() {
             let mut fn_type = FnType::Method;
             match &**method {
@@ -549,6 +929,7 @@ impl<'a> VisitorStmt<()> for Resolver<'a> {
                     }
                     self.resolve_function(&*f, fn_type)?;
                 }
+                Stmt::Signature(_) => {}
                 _ => {
                     panic!("Unexpected statement parsed {method:?}")
                 }
@@ -556,6 +937,7 @@ impl<'a> VisitorStmt<()> for Resolver<'a> {
         }
         self.end_scope();
         self.current_class.replace(prev);
+        self.enclosing_classes.borrow_mut().pop();
         Ok(())
     }
 
@@ -565,4 +947,182 @@ impl<'a> VisitorStmt<()> for Resolver<'a> {
         };
         Ok(())
     }
+
+    fn visit_signature_stmt(&self, _: Rc<Stmt>, _: &SignatureStmt, _: u16) -> Result<(), LoxResult> {
+        Ok(())
+    }
+
+    fn visit_enum_stmt(&self, _: Rc<Stmt>, stmt: &EnumStmt, _: u16) -> Result<(), LoxResult> {
+        self.declare(&stmt.name);
+        self.define(&stmt.name);
+
+        let mut names: Vec<&str> = Vec::new();
+        for variant in stmt.variants.iter() {
+            if let Stmt::Variant(v) = &**variant {
+                if names.contains(&v.name.lexeme.as_str()) {
+                    self.had_error.replace(true);
+                    self.error_handler.error(
+                        &v.name,
+                        LoxErrorsTypes::Syntax("Duplicate variant in enum".to_string()),
+                    );
+                }
+                names.push(&v.name.lexeme);
+            }
+        }
+        Ok(())
+    }
+
+    fn visit_variant_stmt(&self, _: Rc<Stmt>, _: &VariantStmt, _: u16) -> Result<(), LoxResult> {
+        Ok(())
+    }
+
+    fn visit_interface_stmt(
+        &self,
+        _: Rc<Stmt>,
+        stmt: &InterfaceStmt,
+        _: u16,
+    ) -> Result<(), LoxResult> {
+        let mut methods: Vec<(String, usize)> = Vec::new();
+        for method in stmt.methods.iter() {
+            if let Stmt::Signature(s) = &**method {
+                if methods.iter().any(|(name, _)| name == &s.name.lexeme) {
+                    return Err(self.error_handler.error(
+                        &s.name,
+                        LoxErrorsTypes::Syntax("Duplicate method in interface".to_string()),
+                    ));
+                }
+                methods.push((s.name.lexeme.to_string(), s.params.len()));
+            }
+        }
+        match self.interfaces.borrow_mut().last_mut() {
+            Some(scope) => {
+                scope.insert(stmt.name.lexeme.to_string(), methods);
+            }
+            None => self.interpreter.declare_interface(&stmt.name.lexeme, methods),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::{LoxErrorsTypes, LoxWarningTypes},
+        runtime::tests::{eval, run, run_err, warnings},
+    };
+
+    const P: &str = "class P {
+        private s = 1;
+        init() {}
+        public get() { return this.s; }
+        public peek(other) { return other.s; }
+        public fresh() { return P().s; }
+        private m() { return 2; }
+        public call_m() { return this.m(); }
+    }
+    class Q { public s = 2; init() {} }";
+
+    #[test]
+    fn private_inside_class() {
+        let src = format!("{P} let p = P(); let r = [p.get(), p.peek(P()), p.fresh(), p.call_m()];");
+        assert_eq!(eval(&src, "r"), "[1, 1, 1, 2]");
+    }
+
+    #[test]
+    fn private_outside_class() {
+        let expected = LoxErrorsTypes::ReferenceError(
+            "Cannot access private property outside of class 'P' for".to_string(),
+        );
+        assert_eq!(run_err(&format!("{P} P().s;")), expected);
+        let other_class = "class R { init() {} public f() { return P().s; } }";
+        assert_eq!(run_err(&format!("{P} {other_class}")), expected);
+        let field = run_err(&format!("{P} let p = P(); p.s;"));
+        assert!(matches!(field, LoxErrorsTypes::ReferenceError(_)));
+        let method = run_err(&format!("{P} let p = P(); p.m();"));
+        assert!(matches!(method, LoxErrorsTypes::Runtime(_)));
+    }
+
+    #[test]
+    fn private_check_follows_shadowing() {
+        let src = format!("{P} fn f() {{ let P = lm() => Q(); return P().s; }} let r = f();");
+        assert_eq!(eval(&src, "r"), "2");
+        let src = format!("{P} fn f(P) {{ return P().s; }} let r = f(lm() => Q());");
+        assert_eq!(eval(&src, "r"), "2");
+    }
+
+    #[test]
+    fn this_in_field_initializer() {
+        let expected = LoxErrorsTypes::Syntax("Cannot use 'this' in a field initializer".to_string());
+        assert_eq!(run_err("class C { public a = this; init() {} }"), expected);
+        assert_eq!(run_err("class C { public f = lm() => this.a; public a; init() {} }"), expected);
+        let outside = LoxErrorsTypes::Syntax("Cannot use 'this' outside of a class".to_string());
+        assert_eq!(run_err("let f = lm() => this;"), outside);
+    }
+
+    #[test]
+    fn abstract_instantiation() {
+        let expected = LoxErrorsTypes::Type("Cannot instantiate abstract class".to_string());
+        assert_eq!(run_err("abstract class A {} A();"), expected);
+        assert_eq!(run_err("fn f() { abstract class L {} return L(); }"), expected);
+        // Aliases are only caught when the program runs
+        let alias = run_err("abstract class A {} let B = A; B();");
+        let runtime = LoxErrorsTypes::Runtime("Cannot instantiate abstract class 'A' for".to_string());
+        assert_eq!(alias, runtime);
+    }
+
+    #[test]
+    fn abstract_check_follows_shadowing() {
+        let src = "abstract class A {} fn f(A) { return A(); } let r = f(lm() => 3);";
+        assert_eq!(eval(src, "r"), "3");
+        let src = "abstract class A {} fn f() { let A = lm() => 4; return A(); } let r = f();";
+        assert_eq!(eval(src, "r"), "4");
+    }
+
+    #[test]
+    fn interfaces_are_scoped() {
+        let src = "fn f() {
+                interface I { m(a); }
+                class C implements I { init() {} public m(a) { return a; } }
+                return C().m(5);
+            }
+            let r = f();";
+        assert_eq!(eval(src, "r"), "5");
+
+        let src = "fn f() { interface I { m(); } }
+            class C implements I { init() {} public m() {} }";
+        let expected = LoxErrorsTypes::ReferenceError("Undefined interface".to_string());
+        assert_eq!(run_err(src), expected);
+    }
+
+    #[test]
+    fn interface_methods_are_checked() {
+        let shape = "interface Shape { area(); scale(k); }";
+        let missing = "class S implements Shape { init() {} public area() { return 1; } }";
+        assert!(run(&format!("{shape} {missing}")).is_err());
+        let arity = "class S implements Shape { init() {} public area() {} public scale() {} }";
+        assert!(run(&format!("{shape} {arity}")).is_err());
+        let private = "class S implements Shape { init() {} private area() {} public scale(k) {} }";
+        assert!(run(&format!("{shape} {private}")).is_err());
+        let partial = "abstract class S implements Shape { public area() { return 1; } }";
+        assert!(run(&format!("{shape} {partial}")).is_ok());
+    }
+
+    #[test]
+    fn private_check_in_nested_scope() {
+        let expected = LoxErrorsTypes::ReferenceError(
+            "Cannot access private property outside of class 'Local' for".to_string(),
+        );
+        let src = "fn f() { class Local { private s = 1; init() {} } return Local().s; }";
+        assert_eq!(run_err(src), expected);
+    }
+
+    #[test]
+    fn unreachable_match_arm() {
+        let message = "Unreachable match arm after wildcard pattern";
+        let dead = || LoxWarningTypes::DeadCode(message.to_string());
+        let src = "let r = match (1) { _ => 1, 2 => 2, x => x };";
+        assert_eq!(warnings(src), vec![dead(), dead()]);
+        assert!(warnings("let r = match (1) { 2 => 2, x if x > 0 => 3, _ => 1 };").is_empty());
+        assert_eq!(eval("let r = match (2) { _ => 1, 2 => 2 };", "r"), "1");
+    }
 }